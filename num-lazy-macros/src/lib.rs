@@ -0,0 +1,81 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+/*! Procedural macros for num-lazy, re-exported by num-lazy with its `macros` feature.
+
+Use [macro@with_nums] through `num_lazy::with_nums` rather than depending on this crate directly.
+*/
+
+use proc_macro::{Delimiter, Group, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Declare num-lazy macros at the top of the annotated function's body.
+///
+/// `#[with_nums(T)]` inserts `::num_lazy::declare_nums!{T}` into the function, so `pi!()` and the
+/// other macros refer to the function's own `T`. The attribute arguments are forwarded to
+/// `declare_nums!` unchanged, e.g. `#[with_nums(@ops T)]` or `#[with_nums(T, except[half])]`.
+/// Any form usable inside a function body is accepted, which excludes `@module`.
+#[proc_macro_attribute]
+pub fn with_nums(attr: TokenStream, item: TokenStream) -> TokenStream {
+    if attr.is_empty() {
+        return compile_error("expected a type, e.g. `#[with_nums(T)]`", Span::call_site());
+    }
+
+    let mut tokens: Vec<TokenTree> = item.into_iter().collect();
+    let body = match tokens.pop() {
+        Some(TokenTree::Group(body)) if body.delimiter() == Delimiter::Brace => body,
+        other => {
+            let span = other.map_or_else(Span::call_site, |t| t.span());
+            return compile_error("`#[with_nums]` can only be applied to a function", span);
+        }
+    };
+
+    let mut declaration: TokenStream = "::num_lazy::declare_nums!".parse().unwrap();
+    declaration.extend([TokenTree::Group(Group::new(Delimiter::Brace, attr))]);
+    declaration.extend(body.stream());
+
+    let mut new_body = Group::new(Delimiter::Brace, declaration);
+    new_body.set_span(body.span());
+    tokens.push(TokenTree::Group(new_body));
+    tokens.into_iter().collect()
+}
+
+/// Give the name of the `macro_rules!` definition in the input the span of its first token.
+///
+/// `declare_nums!{@strict T}` passes the user's `T` first, so the generated macro is defined
+/// with a name spanned in the user's crate, where rustc reports `unused_macros`. Names spanned
+/// inside num-lazy come from an external macro, which rustc never lints.
+#[doc(hidden)]
+#[proc_macro]
+pub fn strict_macro(input: TokenStream) -> TokenStream {
+    let mut tokens = input.into_iter();
+    let span = match tokens.next() {
+        Some(anchor) => anchor.span(),
+        None => return TokenStream::new(),
+    };
+
+    let mut tokens: Vec<TokenTree> = tokens.collect();
+    for i in 2..tokens.len() {
+        let is_macro_rules =
+            matches!(&tokens[i - 2], TokenTree::Ident(ident) if ident.to_string() == "macro_rules");
+        let is_bang = matches!(&tokens[i - 1], TokenTree::Punct(punct) if punct.as_char() == '!');
+        if let (true, true, TokenTree::Ident(name)) = (is_macro_rules, is_bang, &mut tokens[i]) {
+            name.set_span(span);
+        }
+    }
+    tokens.into_iter().collect()
+}
+
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut path: TokenStream = "::core::compile_error".parse().unwrap();
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    let mut args = Group::new(
+        Delimiter::Parenthesis,
+        TokenTree::Literal(proc_macro::Literal::string(message)).into(),
+    );
+    args.set_span(span);
+    path.extend([TokenTree::Punct(bang), TokenTree::Group(args)]);
+    path
+}
@@ -34,9 +34,51 @@ fn circumference<T: Float>(radius: T) -> T {
 
 See what numbers are declared in [declare_nums].
 
+To keep the macros scoped to a single function, declare them inside its body, where `T` refers to
+the function's own generic parameter. Every form works there except `@module`, since a nested
+module cannot see the generic parameters of the function around it.
+```
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+fn circle_area<T: Float>(radius: T) -> T {
+    declare_nums!{T}
+    pi!() * radius * radius
+}
+#
+# fn main() {
+#     assert!(circle_area(1.0_f64) == core::f64::consts::PI);
+# }
+```
+
+num-lazy is `no_std`. The generated macros only reference `core` paths, `num_traits::NumCast`
+for conversions, and the methods of the type you declared them for, so they can be used in
+`#![no_std]` crates along with `num-traits` without its `std` feature.
+
+The float helpers of `@ops`, `@angle`, and `@approx` (`copysign_one!`, `wrap_angle!`,
+`close_to!`, etc.) call methods like `.abs()` and `.floor()` on your type. Enable the `libm`
+feature to call them through `num_traits::Float` instead, which is backed by `libm` in `no_std`,
+so the helpers work without importing `Float` or depending on `std`.
+
+If you prefer methods over macros, enable the `traits` feature and bound your type with
+[traits::NumLazy] to call `T::pi()`, `T::half()`, etc. without declaring anything.
+
+With the `macros` feature, the `#[with_nums(T)]` attribute declares the macros inside the
+annotated function for you, forwarding its arguments to `declare_nums!`.
+```ignore
+use num_lazy::with_nums;
+use num_traits::Float;
+
+#[with_nums(T)]
+fn circle_area<T: Float>(radius: T) -> T {
+    pi!() * radius * radius
+}
+```
+
 If you `declare_nums!()` in the root of your crate, you don't even need
 to import the macros to submodules. This will not re-export the macros
-to the public crate.
+to the public crate. Use `declare_nums!{@pub T}` instead to export them with
+`#[macro_export]` for downstream crates.
 
 lib.rs or main.rs
 ```ignore
@@ -73,6 +115,16 @@ pub fn circle_area<T: Float>(radius: T) -> T {
     pi!() * radius * radius
 }
 ```
+
+The optional Cargo features are:
+- `alloc`: `num_vec!`, through the `alloc` crate.
+- `half`: `half::f16` and `half::bf16` support, with the `num-traits` feature of `half`.
+- `libm`: call the float methods of the helpers through `num_traits::Float`, backed by `libm`.
+- `log`: `debug_num!`, through `log::trace!`. Also enables `alloc`.
+- `macros`: the `#[with_nums(T)]` attribute and `declare_nums!{@strict T}`.
+- `num-complex`: `declare_nums!{@complex T}`.
+- `traits`: the [traits::NumLazy] trait. Also enables `libm`, since `NumLazy` extends
+  `num_traits::Float`, which `num-traits` only provides with its `std` or `libm` feature.
 */
 
 /// Declare commonly used num generics.
@@ -84,6 +136,7 @@ pub fn circle_area<T: Float>(radius: T) -> T {
 /// // Important. Use the braces!
 /// declare_nums!{T}
 /// // Or declare as needed
+/// // declare_nums!{@fallible T}
 /// // declare_nums!{@literal T}
 /// // declare_nums!{@constant T}
 /// // declare_nums!{@special T}
@@ -101,132 +154,2646 @@ pub fn circle_area<T: Float>(radius: T) -> T {
 ///
 /// Using `declare_nums!{T}` will populate the module with all available macros:
 /// - `num!($n)`: equivalent to `$t::from($n).unwrap()`, where `$t` is the generic type identifier you
-///   declared, and `$n` is any expression evaluated to a number.
+///   declared, and `$n` is any expression evaluated to a number. If the conversion fails, the
+///   panic message includes the stringified expression and the target type name. With two or
+///   more arguments, `num!($a, $b, ...)` is the tuple `(num!($a), num!($b), ...)`, e.g.
+///   `num!(1.0, 2.0)` is a `(T, T)`.
+/// - `nums![$a, $b, ...]`: an array of `num!` conversions, e.g. `nums![1, 2, 3]` is a `[T; 3]`.
+/// - `filled!($n; $len)`: the array `[num!($n); $len]`, e.g. `filled!(0.5; 4)` is a `[T; 4]` of
+///   halves. Requires `T: Copy`, and `$len` must be a const expression.
+/// - `num_vec![$a, $b, ...]`: the same as a `Vec<T>`, requires the `alloc` feature.
+/// - `debug_num!($n)`: the same as `num!($n)`, also logging the expression, its value, the target
+///   type name, and the result with `log::trace!`. Requires the `log` feature, which enables
+///   `alloc`, and `Debug` for the source and target types.
+/// - `num_type_name!()`: the name of the type `T` resolves to, `core::any::type_name::<T>()`,
+///   e.g. `"f64"`, for diagnostics and error messages.
+/// - `num_bits!($bits)`: a value from the raw bits of an `f32` (`u32`) or `f64` (`u64`), e.g.
+///   `num_bits!(0x3FF0000000000000u64)` is `1`. The bit pattern is exact when `T` is the same float
+///   type, and otherwise converted like `num!`. `$bits` must be typed as `u32` or `u64`, e.g. with
+///   a literal suffix, for generic and concrete types alike.
+/// - `from_bool!($b)`: `1` if the `bool` `$b` is `true` and `0` otherwise, with `bool_true!()` and
+///   `bool_false!()` naming the two values, for floats and integers alike.
+/// - `frac!($a, $b)`: equivalent to `$t::from($a).unwrap() / $t::from($b).unwrap()`. The division
+///   is performed in the target type, so `frac!(1, 3)` is `0` for integer types.
+/// - `from_ratio!($a, $b)`: an alias of `frac!`. Integer literals are converted into `T` before
+///   dividing, so `from_ratio!(1, 3)` equals `third!()` for floats.
+/// - `mul_num!($x, $factor)`: `$x * num!($factor)` for `$x` of type `T`, e.g. to scale between
+///   units with `mul_num!(length_km, 1000)`.
+/// - `clamp_num!($n, $lo, $hi)`: convert all three expressions with `num!` and clamp `$n` into
+///   `[$lo, $hi]` with `PartialOrd`. A `NaN` input is returned unchanged.
+/// - `pow!($base, $exp)`, `pow2!($exp)`, and `pow10!($exp)`: raise a number to an `i32` power,
+///   multiplying in the target type instead of converting `f64::powi`. As with any integer
+///   multiplication, a result too large for the type overflows, e.g. `pow2!(8)` for `u8`.
+/// - Fallible conversion as in `declare_nums!{@fallible T}`.
 /// - Literals as in `declare_nums!{@literal T}`.
 /// - Constants as in `declare_nums!{@constant T}`.
 /// - Special as in `declare_nums!{@special T}`.
+/// - Operations as in `declare_nums!{@ops T}`.
+///
+/// Use `declare_nums!{@prefix p_ T}` to declare all macros with a name prefix, e.g. `p_pi!()`
+/// and `p_num!($n)`. This avoids collisions with existing names and allows declaring for
+/// several generic types in the same module.
+/// ```
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{@prefix t_ T}
+/// declare_nums!{@prefix u_ U}
+///
+/// fn half_sum<T: Float, U: Float>(a: T, b: U) -> (T, U) {
+///     (a * t_half!(), b * u_half!())
+/// }
+/// #
+/// # fn main() {
+/// #     assert!(half_sum(1.0_f32, 2.0_f64) == (0.5, 1.0));
+/// # }
+/// ```
+///
+/// To declare for several generic types at once, list them with `declare_nums!{T, U}`. Each
+/// macro is then suffixed with the lowercase type name, e.g. `pi_t!()` and `pi_u!()`. A single
+/// type keeps the un-suffixed names.
+/// ```
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{T, U}
+///
+/// fn convert<T: Float, U: Float>(a: T) -> U {
+///     U::from(a * two_t!()).unwrap() * pi_u!()
+/// }
+/// #
+/// # fn main() {
+/// #     assert!(convert::<f32, f64>(0.5) == core::f64::consts::PI);
+/// # }
+/// ```
+///
+/// Use `declare_nums!{@module nums T}` to keep the macros in a module named `nums` instead of the
+/// calling scope. Each macro is re-exported with `pub(crate) use`, so it can be called by path
+/// from anywhere in the crate, e.g. `nums::pi!()` or `crate::nums::pi!()`.
+/// ```
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{@module nums T}
+///
+/// fn circle_area<T: Float>(radius: T) -> T {
+///     nums::pi!() * radius * radius
+/// }
+/// #
+/// # fn main() {
+/// #     assert!(circle_area(1.0_f64) == core::f64::consts::PI);
+/// # }
+/// ```
+///
+/// Use `declare_nums!{@pub T}` in a library to export every macro with `#[macro_export]`, so
+/// downstream crates can `use my_crate::pi;`. The type identifier is resolved where the macro is
+/// called, so any downstream generic parameter named `T` (or the concrete type) works. As with all
+/// macro-expanded exports, call them by their textual scope rather than `crate::pi!()` within
+/// the declaring crate itself.
+///
+/// Use `declare_nums!{T, except[half, third]}` to declare all macros except the listed names,
+/// e.g. when the module already defines its own `half`. An empty list declares everything. Every
+/// listed name must be one that `declare_nums!{T}` declares, so a misspelled name fails to compile
+/// rather than leaving the macro declared.
+/// ```
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{T, except[half]}
+///
+/// macro_rules! half {
+///     () => { "my own half" };
+/// }
+///
+/// fn quarter_of<T: Float>(a: T) -> T {
+///     a * quarter!()
+/// }
+/// #
+/// # fn main() {
+/// #     assert!(quarter_of(2.0_f64) == 0.5);
+/// #     assert!(half!() == "my own half");
+/// # }
+/// ```
+/// ```compile_fail
+/// use num_lazy::declare_nums;
+/// declare_nums!{T, except[hlaf]}
+/// #
+/// # fn main() {}
+/// ```
+///
+/// Conversely, use `declare_nums!{T, only[pi, tau, half]}` to declare only the listed names. Every
+/// name must be one that `declare_nums!{T}` declares, otherwise the declaration fails to compile.
+/// ```
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{T, only[pi, half]}
+///
+/// fn half_pi<T: Float>() -> T {
+///     half!() * pi!()
+/// }
+/// #
+/// # fn main() {
+/// #     assert!(half_pi::<f64>() == core::f64::consts::FRAC_PI_2);
+/// # }
+/// ```
+/// ```compile_fail
+/// use num_lazy::declare_nums;
+/// declare_nums!{T, only[pi, tua]}
+/// #
+/// # fn main() {}
+/// ```
+///
+/// Use `declare_nums!{@via my_conv T}` to convert with a function of your own instead of
+/// `num_traits::NumCast`, for types without a `num_traits` impl. Every conversion calls
+/// `my_conv($n)`, which may return an `Option` or a `Result`, e.g. `T::try_from`.
+/// ```
+/// use num_lazy::declare_nums;
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Cents(i64);
+///
+/// impl TryFrom<i64> for Cents {
+///     type Error = ();
+///
+///     fn try_from(n: i64) -> Result<Self, ()> {
+///         n.checked_mul(100).map(Cents).ok_or(())
+///     }
+/// }
+///
+/// declare_nums!{@via Cents::try_from Cents}
+///
+/// fn main() {
+///     assert!(num!(5) == Cents(500));
+///     assert!(try_num!(i64::MAX) == None);
+/// }
+/// ```
+///
+/// The type may also be concrete, such as `declare_nums!{f32}` or `declare_nums!{f64}`, to use the
+/// same macros in non-generic code. The `@special` macros call the methods of `num_traits::Float`,
+/// so bring `Float` (or `FloatCore`) into scope as you would bound a generic type with it.
+/// For `f32` and `f64`, the literal and constant macros expand to plain `const` expressions, such as
+/// `core::f64::consts::PI`, so they can also initialize `const` and `static` items.
+/// ```
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{f32}
+///
+/// const HALF_PI: f32 = half!() * pi!();
+///
+/// fn main() {
+///     assert!(pi!() == core::f32::consts::PI);
+///     assert!(HALF_PI == core::f32::consts::FRAC_PI_2);
+///     assert!(half!() * inf!() == f32::INFINITY);
+/// }
+/// ```
+///
+/// A path-qualified type, such as a type alias in another module, is accepted by the plain form
+/// `declare_nums!{crate::types::Scalar}`. The other forms take a single identifier, so `use` the
+/// type first for them. A path is never matched as `f32` or `f64`, so its literals and constants
+/// are converted at runtime even when it names one of them.
+/// ```
+/// mod types {
+///     pub type Scalar = f64;
+/// }
+///
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{types::Scalar}
+///
+/// fn main() {
+///     assert!(tau!() == core::f64::consts::TAU);
+/// }
+/// ```
+///
+/// Any type implementing `num_traits::Float` is supported, including `half::f16` and `half::bf16`
+/// when `half` is built with its `num-traits` feature (also enabled by num-lazy's `half` feature).
+///
+/// Types implementing `num_traits::FromPrimitive` but not `NumCast`, such as `rust_decimal::Decimal`,
+/// are converted through `FromPrimitive` instead: integral values with `from_i64` or `from_u64`,
+/// and others with `from_f64`. `num!`, `@fallible`, and `@literal` then work for a generic
+/// `T: FromPrimitive` or a concrete `declare_nums!{Decimal}`, while `@special` still requires
+/// `Float`. Fractional literals and constants pass through `f64`, so `third!()` is rounded to
+/// `f64` precision first.
+///
+/// Arbitrary-precision integers such as `num_bigint::BigInt` and `BigUint` take the same
+/// `FromPrimitive` path, so `num!`, `@fallible`, `@literal`, and `@integer` work for them. Prefer
+/// `declare_nums!{@integer T}` over `@literal`, as fractional literals like `half!()` truncate to
+/// `0`. Macros that copy their operands, such as `square!`, require `T: Copy` and cannot be used
+/// with these types, but declaring them does no harm as long as they are unused.
+///
+/// Every generated macro carries `#[allow(unused_macros)]`. To keep declarations lean, declare
+/// only the groups you need, drop names with `except[...]`, or keep just a few with `only[...]`.
+/// To find the unused ones, use `declare_nums!{@strict T}` with the `macros` feature. It declares
+/// the same macros as `declare_nums!{T}` without the `allow`, so rustc reports each unused one as
+/// `unused_macros`, pointing at the `T` of the declaration.
+#[cfg_attr(feature = "macros", doc = "```compile_fail")]
+#[cfg_attr(not(feature = "macros"), doc = "```ignore")]
+/// #![deny(unused_macros)]
+/// use num_lazy::declare_nums;
+/// declare_nums!{@strict f64}
+///
+/// fn main() {
+///     assert!(pi!() == core::f64::consts::PI);
+/// }
+/// ```
 ///
 /// Each match arm will populate the module with:
+/// - **Fallible:** `declare_nums!{@fallible T}`
+///     - `try_num!($n)`: equivalent to `$t::from($n)`, returning `Option<T>` instead of panicking.
+///     - `num_or!($n, $default)`: equivalent to `$t::from($n).unwrap_or($default)`.
+///     - `num_checked!($n)`: `$n` converted into `Result<T, NumLazyError>`, with
+///       [NumLazyError::OutOfRange] for values outside the range of `T` and
+///       [NumLazyError::NotRepresentable] for `NaN`, so it can be used with `?`.
+///     - `saturating_num!($n)`: `$n` converted into `T`, or `T::min_value()` or `T::max_value()`
+///       when it is out of range, and `0` for `NaN`. This matches `$n as T` for primitive types,
+///       e.g. `saturating_num!(300)` is `255` for `u8`. The bounds come from `Float` or `Bounded`,
+///       whichever is in scope.
 /// - **Literals:** `declare_nums!{@literal T}`
 ///     - `zero!()` to `ten!()`
-///     - `hundred!()`, `thousand!()`, and `million!()`
+///     - `neg_one!()` to `neg_ten!()`
+///     - `hundred!()`, `thousand!()`, `ten_thousand!()`, `hundred_thousand!()`, `million!()`,
+///       and `billion!()`. Like `num!`, these panic if the value does not fit, e.g. `billion!()`
+///       for `i16` or `u16`.
 ///     - `half!()`, `third!()`, and `quarter!()`
+///     - `two_thirds!()`, `three_quarters!()`, `five_sixths!()`, and `three_halves!()`
 ///     - `tenth!()`, `hundredth!()`, `thousandth!()`, and `millionth!()`
+///     - `percent!($x)`: `$x / 100`, e.g. `percent!(5)` is `0.05`. Integer types truncate, so
+///       `percent!(50)` is `0`.
 /// - **Constants:** `declare_nums!{@constant T}`
-///     - `pi!()`, `pi_2!()`, `pi_3!()`, `frac_1_pi!()`, `frac_2_pi!()`, and `frac_2_sqrt_pi!()`
+///     - `pi!()`, `pi_2!()`, `pi_3!()`, `pi_4!()`, `pi_6!()`, `frac_1_pi!()`, `frac_2_pi!()`, and
+///       `frac_2_sqrt_pi!()`
+///     - Multiples of π: `three_pi_2!()` (3π/2) and `four_pi!()` (4π)
 ///     - `tau!()`
 ///     - `e!()`
 ///     - `ln_2!()`, `ln_10!()`, `log2_10!()`, `log2_e!()`, `log10_2!()`, and `log10_e!()`
-///     - `sqrt_2!()` and `frac_1_sqrt_2!()`
+///     - Logarithms of constants: `ln_pi!()`, `log10_pi!()`, and `ln_phi!()`
+///     - Squares of constants: `pi_squared!()`, `e_squared!()`, and `tau_squared!()`
+///     - `sqrt_2!()`, `frac_1_sqrt_2!()`, `sqrt_3!()`, `sqrt_5!()`, `sqrt_pi!()`, and `sqrt_half_pi!()`
+///     - Γ(1/2): `gamma_half!()`, equal to `sqrt_pi!()`
 ///     - The golden ratio: `phi!()`
+///     - Metallic means: `phi_conjugate!()` (ψ = 1 - φ) and `silver_ratio!()` (1 + sqrt(2)).
+///       The conjugate is the negative root of `x² = x + 1`, `-0.6180339887498949`, which is
+///       `-frac_1_phi!()`. Use `frac_1_phi!()` for the positive value.
+///     - Reciprocals: `frac_1_e!()`, `frac_1_tau!()`, and `frac_1_phi!()`
+///     - The Euler–Mascheroni constant `euler_gamma!()`, Catalan's constant `catalan!()`, and
+///       Apéry's constant `apery!()`
+///     - Constants are stored as `f64` values and converted into the target type, so `f32` only
+///       keeps the nearest representable value (about 7 significant digits). None of the `f64`
+///       values lies halfway between two `f32` values, so the conversion is not double-rounded
+///       and each `f32` constant is bit-equal to its counterpart in `core::f32::consts`.
 /// - **Special Constants:** `declare_nums!{@special T}`
 ///     - Infinity: `inf!()` and `neg_inf!()`
 ///     - `nan!()`
 ///     - Min/max type representation value: `min_val!()`, `max_val!()`, and `min_positive!()`
+///       `min_val!()` and `max_val!()` call `$t::min_value()` and `$t::max_value()`, which resolve
+///       to `Float` for floats and to `Bounded`, a supertrait of `PrimInt`, for integers, so they
+///       also work for unsigned types, e.g. `max_val!()` is `u8::MAX` for `u8`. Bounding `T` by
+///       `Bounded` directly would exclude `T: Float`, which does not imply it.
+///     - `tiny!()`, the smallest positive normal value (same as `min_positive!()`), and
+///       `smallest_subnormal!()`, `2^-149` for `f32` and `2^-1074` for `f64`. Platforms that
+///       flush subnormals to zero return `0` for `smallest_subnormal!()`.
+///     - `next_up!($x)` and `next_down!($x)`: the adjacent representable values above and below
+///       `$x`, so `next_up!(one!()) - one!() == epsilon!()`. They step between `max_val!()` and
+///       infinity, return infinities in the stepping direction and `NaN` unchanged, and step from
+///       either zero to `±smallest_subnormal!()`.
 ///     - Machine epsilon: `epsilon!()`
+///     - `half_epsilon!()`, the unit roundoff `epsilon!() / 2`, and `sqrt_epsilon!()`, a common
+///       finite-difference step. `sqrt_epsilon!()` calls `sqrt` and so requires `T: Float`.
+///     - `max_safe_int!()`, the largest integer up to which every integer is exactly representable,
+///       `2^mantissa_digits!()`: `16777216` (2^24) for `f32` and `9007199254740992` (2^53) for `f64`.
+///       `max_safe_int!() + one!()` rounds back to `max_safe_int!()`.
 ///     - Negative zero: `neg_zero!()`
+///     - Predicates: `is_finite!($x)`, `is_nan!($x)`, and `is_inf!($x)`, calling `is_finite`,
+///       `is_nan`, and `is_infinite` of `Float`
+///     - Floating-point characteristics as integers, not `T`: `radix!()`, `mantissa_digits!()`,
+///       and `digits!()` as `u32`, `min_exp!()` and `max_exp!()` as `i32`. These match the
+///       associated constants of `f32`, `f64`, and `half::f16`, computed through `integer_decode`.
+/// - **Operations:** `declare_nums!{@ops T}`, taking values of type `T`
+///     - `recip!($x)`: `$t::one() / $x`
+///     - `square!($x)`: `$x * $x`
+///     - `cube!($x)`: `$x * $x * $x`
+///     - `pow_n!($x, $n)`: `num_traits::pow($x, $n)` for `$n: usize`, requiring only
+///       `Clone + One + Mul` rather than `Float::powi` or `PrimInt::pow`. Unlike `pow!`, `$x` is
+///       already a `T` and negative exponents are not accepted.
+///     - `powf!($base, $exp)`: `num!($base).powf(num!($exp))`, for floats only. Both operands go
+///       through `num!`, so they may be literals, e.g. `powf!(2, 0.5)`, or values of type `T`,
+///       which convert into themselves unchanged.
+///     - `fma!($a, $b, $c)`: `$a * $b + $c` through `Float::mul_add`, a fused multiply-add with a
+///       single rounding, called like the other float helpers (through `num_traits::Float` with
+///       the `libm` feature). Without `libm`, integer types with a `T: MulAdd<Output = T>` bound
+///       also work and compute plain `$a * $b + $c`.
+///     - `lerp!($a, $b, $x)`: `$a + ($b - $a) * $x`
+///     - `midpoint!($a, $b)`: `($a + $b) / 2`, computed in `T` (and truncated for integers)
+///     - `copysign_one!($x)`: `$t::one().copysign($x)`, for floats only. `-0.0` gives `-1`, and a
+///       `NaN` gives `1` or `-1` according to its sign bit.
+///     - `signum!($x)`: `$x.signum()` of `Float` or `num_traits::Signed`, whichever is in scope.
+///       For floats this follows `Float::signum`, so `0.0` gives `1`, `-0.0` gives `-1`, and `NaN`
+///       gives `NaN`. Signed integers give `-1`, `0`, or `1`.
+///     - `sign_of!($x)`: `-1`, `0`, or `1` according to the sign of `$x`. `-0.0` and `NaN` are
+///       returned unchanged. Unlike `signum!`, this only requires `PartialOrd`, so it also works for
+///       unsigned integers.
+///     - `is_positive!($x)`, `is_negative!($x)`, and `is_zero!($x)`: `$x > 0`, `$x < 0`, and
+///       `$x == 0`. `-0.0` is zero but neither positive nor negative, and `NaN` is none of them.
+///     - `horner!($x; c0, c1, c2, ...)`: the polynomial `c0 + c1 * $x + c2 * $x^2 + ...` evaluated
+///       as `c0 + $x * (c1 + $x * (c2 + ...))`, converting each coefficient with `num!`
+///     - `rem_euclid!($a, $b)` and `div_euclid!($a, $b)`: Euclidean remainder and division, so the
+///       remainder is never negative. These require `T: num_traits::Euclid`, which is implemented
+///       for every primitive integer and float but is not implied by `Float` or `PrimInt`.
+///     - `checked_div!($a, $b)`: `Some($a / $b)`, or `None` when `$b` is zero (including `-0.0`)
+///       or the quotient overflows, which is only `min_val!() / -1` for signed integers. Division
+///       by zero gives `inf` or `NaN` for floats, while both cases panic for integers, so
+///       `checked_div!` never panics and never gives `inf` or `NaN` from a zero divisor.
+///     - `sat_add!($a, $b)` and `sat_mul!($a, $b)`: `$a + $b` and `$a * $b` for integers, clamped to
+///       `T::min_value()` or `T::max_value()` instead of overflowing, e.g.
+///       `sat_add!(max_val!(), one!())` is `max_val!()`. These require `T: CheckedAdd + Bounded`
+///       and `T: CheckedMul + Bounded`, which `PrimInt` implies.
+///     - `max_of!($a, $b, ...)` and `min_of!($a, $b, ...)`: the largest and smallest of one or more
+///       values. As with `Float::max` and `Float::min`, `NaN` values are ignored unless all are `NaN`.
+///     - `abs_diff!($a, $b)`: `($a - $b).abs()`, requiring only `PartialOrd` and `Sub`, so it also
+///       works for unsigned integers. A `NaN` operand gives `NaN`.
+///     - `clamp_to_unit!($x)`: `$x` clamped into `[0, 1]`. A `NaN` input is returned unchanged.
+///     - `clamp_nonneg!($x)` and `clamp_nonpos!($x)`: `max($x, 0)` and `min($x, 0)`, e.g. for a
+///       ReLU. As with `clamp_to_unit!`, a `NaN` input is returned unchanged.
+///     - `wrap_unit!($x)`: `$x - $x.floor()`, tiling `$x` into `[0, 1)` rather than clamping it, for
+///       floats only. Negative inputs wrap around, e.g. `-0.25` gives `0.75`, and exact integers
+///       (including negative ones) give `0`. Infinities and `NaN` give `NaN`.
+///     - `round_to!($x, $digits)`: `$x` rounded to `$digits` decimal places, for floats only.
+///       Halfway cases round away from zero as with `Float::round`, not to even (no banker's
+///       rounding).
+///     - `linspace_step!($start, $stop, $n)`: `($stop - $start) / ($n - 1)`, the step between `$n`
+///       evenly spaced values from `$start` to `$stop`. `$n == 1` divides by zero.
+///
+/// The following arms are not included in `declare_nums!{T}` and must be declared explicitly:
+/// - **Integers:** `declare_nums!{@integer T}`, an alternative to `@literal` for `PrimInt` types
+///   that converts integer literals (`$t::from(5).unwrap()`) instead of float literals
+///     - `zero!()` to `ten!()`
+///     - `hundred!()`, `thousand!()`, and `million!()`
+/// - **Angles:** `declare_nums!{@angle T}`, in radians
+///     - `deg_30!()`, `deg_45!()`, `deg_60!()`, `deg_90!()`, `deg_180!()`, `deg_270!()`, and `deg_360!()`
+///     - `deg_per_rad!()` (180/π) and `rad_per_deg!()` (π/180)
+///     - `deg_to_rad!($x)` and `rad_to_deg!($x)` to convert a value of type `T`
+///     - `to_radians!($x)` and `to_degrees!($x)`: the same through `Float::to_radians` and
+///       `Float::to_degrees`, for floats only
+///     - `deg!($x)` to convert any number of degrees, e.g. `deg!(30)`, into radians of type `T`
+///     - `wrap_angle!($x)` to wrap an angle of type `T` into `[-π, π)`. Exactly `π` (and any odd
+///       multiple of it) wraps to `-π`.
+/// - **Trigonometric Values:** `declare_nums!{@trig T}`, exact values at common angles
+///     - `sin_30!()`, `cos_30!()`, and `tan_30!()`
+///     - `sin_45!()`, `cos_45!()`, and `tan_45!()`
+///     - `sin_60!()`, `cos_60!()`, and `tan_60!()`
+/// - **Statistics:** `declare_nums!{@stats T}`
+///     - `sqrt_2pi!()`, `frac_1_sqrt_2pi!()`, and `ln_2pi!()`
+///     - `half_ln_2pi!()`, ln(2π)/2, the constant term of Stirling's approximation of ln Γ(x)
+///     - `mean!($a, $b, ...)`: the arithmetic mean of one or more values of type `T`, summed in `T`
+///       and divided by the count converted with `num!`. The sum can overflow for many large
+///       values, and integer types truncate the quotient.
+/// - **Physical Constants:** `declare_nums!{@physics T}`, using CODATA 2018 values
+///     - Speed of light: `speed_of_light!()`
+///     - Planck constant: `planck!()`
+///     - Gravitational constant: `grav_const!()`
+///     - Boltzmann constant: `boltzmann!()`
+///     - Avogadro constant: `avogadro!()`
+///     - Elementary charge: `elementary_charge!()`
+/// - **Astronomical Constants:** `declare_nums!{@astro T}`, in SI units
+///     - Astronomical unit: `au!()`
+///     - Julian year in seconds: `julian_year!()`
+///     - Solar mass (IAU 2015 estimate): `solar_mass!()`
+///     - Standard gravity: `standard_gravity!()`
+/// - **Thermodynamic Constants:** `declare_nums!{@thermo T}`, in SI units
+///     - Molar gas constant: `gas_constant!()`
+///     - Stefan–Boltzmann constant: `stefan_boltzmann!()`
+///     - Standard atmosphere in pascals: `standard_atm!()`
+///     - Absolute zero in degrees Celsius: `absolute_zero_c!()`
+/// - **Electromagnetic Constants:** `declare_nums!{@em T}`, using CODATA 2018 values
+///     - Vacuum electric permittivity: `epsilon_0!()`
+///     - Vacuum magnetic permeability: `mu_0!()`
+///     - Characteristic impedance of vacuum: `impedance_free_space!()`
+/// - **Approximate Equality:** `declare_nums!{@approx T}`
+///     - `close_to!($a, $b)`: whether `$a` and `$b` differ by at most `epsilon!()`, scaled by the
+///       larger magnitude of the two (or `1` if both are smaller), returning `bool`
+///     - `close_to!($a, $b, $tol)`: the same with a custom tolerance `$tol` of type `T`
+///     - `is_negligible!($x)`: whether `$x.abs() < epsilon!()`, e.g. before dividing by `$x`
+///     - `nan_safe_eq!($a, $b)`: `$a == $b`, except that `NaN` equals `NaN`. This departs from
+///       IEEE 754, where `NaN` is not equal to anything, including itself.
+/// - **Parsing:** `declare_nums!{@parse T}`, requires `T: num_traits::Num`
+///     - `from_str_num!($s)`: parse a `&str` in base 10 into `Result<T, T::FromStrRadixErr>`
+/// - **Complex Numbers:** `declare_nums!{@complex T}`, requires the `num-complex` feature
+///     - `complex!($re, $im)`: `num_complex::Complex::<T>::new` with both parts converted into `T`
+///     - The imaginary unit: `i!()`
+///     - `complex_zero!()` and `complex_one!()`
+///
+/// `declare_nums!{@extended T}` declares both `@stats` and `@angle` in one call. Do not also
+/// declare either of them in the same scope, as the duplicate definitions make the macro names
+/// ambiguous.
+///
+/// `declare_nums!{@named T, name = expr, ...}` declares your own literal macros, each converting
+/// its expression like the built-in literals. For `f32` and `f64` they can be used in `const`
+/// contexts as well.
+/// ```
+/// use num_lazy::declare_nums;
+/// use num_traits::Float;
+/// declare_nums!{@named T, gravity = 9.81, c = 2.998e8}
+///
+/// fn fall_time<T: Float>(height: T) -> T {
+///     (height * (T::one() + T::one()) / gravity!()).sqrt()
+/// }
+/// #
+/// # fn main() {
+/// #     assert!(fall_time(4.905_f64) == 1.0);
+/// # }
+/// ```
+///
+/// `declare_nums!{@names T}` declares no macros but a `&[&str]` constant per group listing the
+/// names it defines, without prefix or suffix: `NUM_LAZY_NUMS`, `NUM_LAZY_FALLIBLE`,
+/// `NUM_LAZY_LITERALS`, `NUM_LAZY_CONSTANTS`, `NUM_LAZY_SPECIALS`, `NUM_LAZY_OPS`,
+/// `NUM_LAZY_INTEGERS`, `NUM_LAZY_ANGLES`, `NUM_LAZY_TRIG`, `NUM_LAZY_STATS`, `NUM_LAZY_PHYSICS`,
+/// `NUM_LAZY_ASTRO`, `NUM_LAZY_THERMO`, `NUM_LAZY_EM`, `NUM_LAZY_APPROX`, `NUM_LAZY_PARSE`, and
+/// `NUM_LAZY_COMPLEX`. The names do not depend on `T`.
+/// ```
+/// use num_lazy::declare_nums;
+/// declare_nums!{@names T}
+///
+/// assert!(NUM_LAZY_LITERALS.contains(&"half"));
+/// assert!(NUM_LAZY_OPS.contains(&"lerp"));
+/// ```
 #[macro_export]
 macro_rules! declare_nums {
     {$t: ident} => {
-        /// Unwrap the expression into the specified generic type.
-        ///
-        /// Equivalent to `$t::from($n).unwrap()`, where `$t` is the generic type identifier you
-        /// declared, and `$n` is any expression evaluated to a number.
-        #[allow(unused_macros)]
-        macro_rules! num {
-            ($n: expr) => {
-                $t::from($n).unwrap()
-            };
+        $crate::_declare_nums!{@all [$t; () () () () ()]}
+    };
+    {$t:ident, except[]} => {
+        $crate::declare_nums!{$t}
+    };
+    {$t:ident, except[$($x:ident),+ $(,)?]} => {
+        $crate::_declare_except!{[$t; () () () ()] [$($x),+] $}
+    };
+    {$t:ident, only[$($x:ident),+ $(,)?]} => {
+        $crate::_declare_only!{[$t; () () () ()] [$($x),+] $}
+    };
+    {$($t:ident),+} => {
+        $($crate::_declare_nums!{@all [$t; () () (_ $t:lower) () ()]})+
+    };
+    {@prefix $p:ident $t:ident} => {
+        $crate::_declare_nums!{@all [$t; () ($p) () () ()]}
+    };
+    {@pub $t:ident} => {
+        $crate::_declare_nums!{@all [$t; () () () (#[macro_export]) ()]}
+    };
+    {@strict $t:ident} => {
+        $crate::_declare_strict!{$t}
+    };
+    {@via $($conv:ident)::+ $t:ident} => {
+        $crate::_declare_nums!{@all [$t; ($($conv)::+) () () () ()]}
+    };
+    {@module $m:ident $t:ident} => {
+        mod $m {
+            $crate::_declare_nums!{@all [$t; () () () (pub(crate)) ()]}
         }
-
-        declare_nums!{@literal $t}
-        declare_nums!{@constant $t}
-        declare_nums!{@special $t}
+    };
+    {@fallible $t:ident} => {
+        $crate::_declare_nums!{@fallible [$t; () () () () ()]}
     };
     {@literal $t:ident} => {
-        macro_rules! _declare_literal {
-            ($name:ident, $n: expr, $doc: expr) => {
-                #[allow(unused_macros)]
-                #[doc=$doc]
-                macro_rules! $name {
-                    () => {
-                        $t::from($n).unwrap()
-                    };
-                }
+        $crate::_declare_nums!{@literal [$t; () () () () ()]}
+    };
+    {@constant $t:ident} => {
+        $crate::_declare_nums!{@constant [$t; () () () () ()]}
+    };
+    {@special $t:ident} => {
+        $crate::_declare_nums!{@special [$t; () () () () ()]}
+    };
+    {@ops $t:ident} => {
+        $crate::_declare_nums!{@ops [$t; () () () () ()] $}
+    };
+    {@integer $t:ident} => {
+        $crate::_declare_nums!{@integer [$t; () () () () ()]}
+    };
+    {@angle $t:ident} => {
+        $crate::_declare_nums!{@angle [$t; () () () () ()]}
+    };
+    {@trig $t:ident} => {
+        $crate::_declare_nums!{@trig [$t; () () () () ()]}
+    };
+    {@stats $t:ident} => {
+        $crate::_declare_nums!{@stats [$t; () () () () ()] $}
+    };
+    {@physics $t:ident} => {
+        $crate::_declare_nums!{@physics [$t; () () () () ()]}
+    };
+    {@extended $t:ident} => {
+        $crate::_declare_nums!{@stats [$t; () () () () ()] $}
+        $crate::_declare_nums!{@angle [$t; () () () () ()]}
+    };
+    {@astro $t:ident} => {
+        $crate::_declare_nums!{@astro [$t; () () () () ()]}
+    };
+    {@thermo $t:ident} => {
+        $crate::_declare_nums!{@thermo [$t; () () () () ()]}
+    };
+    {@em $t:ident} => {
+        $crate::_declare_nums!{@em [$t; () () () () ()]}
+    };
+    {@approx $t:ident} => {
+        $crate::_declare_nums!{@approx [$t; () () () () ()]}
+    };
+    {@parse $t:ident} => {
+        $crate::_declare_nums!{@parse [$t; () () () () ()]}
+    };
+    {@complex $t:ident} => {
+        $crate::_declare_complex!{[$t; () () () () ()]}
+    };
+    {@names $t:ident} => {
+        $crate::_declare_names!{}
+    };
+    {@named $t:ident, $($name:ident = $n:expr),+ $(,)?} => {
+        $($crate::_declare_literal!{[$t; () () () () ()] $name, $n, ::core::concat!("`", ::core::stringify!($n), "`")})+
+    };
+    {$t:path} => {
+        $crate::_declare_nums!{@all [$t; () () () () ()]}
+    };
+}
+
+#[cfg(feature = "traits")]
+pub mod traits;
+
+#[cfg(feature = "macros")]
+pub use num_lazy_macros::with_nums;
+
+/// The error returned by `num_checked!` when a value cannot be converted into the target type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum NumLazyError {
+    /// The value is outside the range of the target type, e.g. `300` for `u8`.
+    OutOfRange,
+    /// The value has no counterpart in the target type, e.g. `NaN` for an integer type.
+    NotRepresentable,
+}
+
+impl core::fmt::Display for NumLazyError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            NumLazyError::OutOfRange => f.write_str("value is out of range of the target type"),
+            NumLazyError::NotRepresentable => {
+                f.write_str("value is not representable in the target type")
+            }
+        }
+    }
+}
+
+impl core::error::Error for NumLazyError {}
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub extern crate alloc as __alloc;
+#[cfg(feature = "log")]
+#[doc(hidden)]
+pub use log as __log;
+#[cfg(feature = "num-complex")]
+#[doc(hidden)]
+pub use num_complex as __num_complex;
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+pub use num_lazy_macros::strict_macro as __strict_macro;
+#[doc(hidden)]
+pub use num_traits as __num_traits;
+#[doc(hidden)]
+pub use pastey::paste as __paste;
+
+/// Populate the module with every group of macros for a declaration config.
+///
+/// The config is a bracketed list `[$t; ($conv) ($prefix) ($suffix) ($vis) ($filter)]` threaded to
+/// every generated macro. A non-empty `$conv` is the conversion function used instead of `NumCast`,
+/// a non-empty `$vis` re-exports each macro with `$vis use` (or, for `@strict`, leaves out
+/// `#[allow(unused_macros)]`), and a non-empty `$filter` passes each definition through the named
+/// filter macro.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_nums {
+    {@all $cfg:tt} => {
+        $crate::_declare_nums!{@num $cfg $}
+        $crate::_declare_nums!{@fallible $cfg}
+        $crate::_declare_nums!{@literal $cfg}
+        $crate::_declare_nums!{@constant $cfg}
+        $crate::_declare_nums!{@special $cfg}
+        $crate::_declare_nums!{@ops $cfg $}
+    };
+    {@num [$t:tt; $conv:tt $($cfg:tt)*] $d:tt} => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] num,
+            "Unwrap the expression into the specified generic type.\n\n\
+            Equivalent to `$t::from($n).unwrap()`, where `$t` is the generic type identifier you \
+            declared, and `$n` is any expression evaluated to a number. On failure, the panic \
+            message names the expression and the target type.\n\n\
+            With two or more expressions, `num!($a, $b, ...)` builds a tuple `(num!($a), num!($b), ...)`.",
+            {
+                ($n: expr) => {
+                    $crate::_num!($conv, $t, $n)
+                };
+                ($n: expr, $d($d rest: expr),+ $d(,)?) => {
+                    ($crate::_num!($conv, $t, $n) $d(, $crate::_num!($conv, $t, $d rest))+)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] nums,
+            "Build an array by unwrapping each expression into the specified generic type.\n\n\
+            Equivalent to `[$t::from($a).unwrap(), $t::from($b).unwrap(), ...]`.",
+            {
+                ($d($d n: expr),* $d(,)?) => {
+                    [$d($crate::_from!($conv, $t, $d n).unwrap()),*]
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] filled,
+            "Build an array repeating an expression unwrapped into the specified generic type.\n\n\
+            Equivalent to `[$t::from($n).unwrap(); $len]`. The conversion happens once, so `$t` \
+            must be `Copy`, and `$len` must be a const expression such as a literal or a const \
+            generic parameter.",
+            {
+                ($n: expr; $len: expr) => {
+                    [$crate::_from!($conv, $t, $n).unwrap(); $len]
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] num_vec,
+            "Build a `Vec` by unwrapping each expression into the specified generic type.\n\n\
+            Equivalent to `vec![$t::from($a).unwrap(), $t::from($b).unwrap(), ...]`. Requires the \
+            `alloc` feature.",
+            {
+                ($d($d n: expr),* $d(,)?) => {
+                    $crate::_num_vec!($conv, $t; $d($d n),*)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] debug_num,
+            "Unwrap the expression into the specified generic type and log the conversion.\n\n\
+            Equivalent to `num!($n)`, additionally emitting a `log::trace!` with the expression, its \
+            value, the target type name, and the result. Requires the `log` feature.",
+            {
+                ($n: expr) => {
+                    $crate::_debug_num!($conv, $t, $n)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] num_type_name,
+            "The name of the type that the specified generic type resolves to, as `&'static str`.\n\n\
+            Equivalent to `core::any::type_name::<$t>()`, e.g. `\"f64\"`. The exact string is only \
+            meant for diagnostics and may differ between compiler versions.",
+            {
+                () => {
+                    ::core::any::type_name::<$t>()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] num_bits,
+            "Build a value of the specified generic type from a raw float bit pattern.\n\n\
+            A `u32` is read as the bits of an `f32` and a `u64` as the bits of an `f64`, which is \
+            then unwrapped into the target type. Declared for `f32` or `f64`, this accepts the same \
+            `u32` or `u64` and can be used in `const` contexts.",
+            {
+                ($bits: expr) => {
+                    $crate::_num_bits!($t, $conv, $bits)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] from_bool,
+            "Convert a `bool` into `1` or `0` of the specified generic type.\n\n\
+            Equivalent to `if $b { $t::one() } else { $t::zero() }`.",
+            {
+                ($b: expr) => {
+                    if $b {
+                        <$t as $crate::__num_traits::One>::one()
+                    } else {
+                        <$t as $crate::__num_traits::Zero>::zero()
+                    }
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] bool_true,
+            "`true` encoded as a number of the specified generic type, `$t::one()`.",
+            {
+                () => {
+                    <$t as $crate::__num_traits::One>::one()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] bool_false,
+            "`false` encoded as a number of the specified generic type, `$t::zero()`.",
+            {
+                () => {
+                    <$t as $crate::__num_traits::Zero>::zero()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] frac,
+            "Divide two expressions after unwrapping each into the specified generic type.\n\n\
+            Equivalent to `$t::from($a).unwrap() / $t::from($b).unwrap()`, so the division is \
+            performed in the target type rather than in `f64`.",
+            {
+                ($a: expr, $b: expr) => {
+                    $crate::_from!($conv, $t, $a).unwrap() / $crate::_from!($conv, $t, $b).unwrap()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] from_ratio,
+            "Divide two expressions after unwrapping each into the specified generic type, an \
+            alias of `frac!`.\n\n\
+            Both operands are converted before dividing, so `from_ratio!(1, 3)` is `1/3` for floats \
+            even though `1 / 3` is `0` in `i32`. The division still truncates for integer types.",
+            {
+                ($a: expr, $b: expr) => {
+                    $crate::_from!($conv, $t, $a).unwrap() / $crate::_from!($conv, $t, $b).unwrap()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] mul_num,
+            "Multiply a value of the specified generic type by an expression unwrapped into it.\n\n\
+            Equivalent to `$x * $t::from($factor).unwrap()`, e.g. `mul_num!(length_km, 1000)` for \
+            a unit conversion.",
+            {
+                ($x: expr, $factor: expr) => {{
+                    let x: $t = $x;
+                    x * $crate::_from!($conv, $t, $factor).unwrap()
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] clamp_num,
+            "Unwrap three expressions into the specified generic type and clamp the first into \
+            `[$lo, $hi]`.\n\n\
+            The comparison uses `PartialOrd`, so a `NaN` input is returned unchanged.",
+            {
+                ($n: expr, $lo: expr, $hi: expr) => {{
+                    let n: $t = $crate::_from!($conv, $t, $n).unwrap();
+                    let lo: $t = $crate::_from!($conv, $t, $lo).unwrap();
+                    let hi: $t = $crate::_from!($conv, $t, $hi).unwrap();
+                    if n < lo {
+                        lo
+                    } else if n > hi {
+                        hi
+                    } else {
+                        n
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] pow,
+            "Raise `$base` to the integer power `$exp: i32` in the specified generic type.\n\n\
+            `$base` is unwrapped into the target type and raised by squaring, so no `f64` \
+            intermediate is involved. Negative exponents divide `1` by the power, in chunks small \
+            enough not to overflow, so floats keep results down to the subnormal range. For \
+            integer types the reciprocal truncates to `0`.",
+            {
+                ($base: expr, $exp: expr) => {
+                    $crate::_pow!($conv, $t, $base, $exp)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] pow10,
+            "Raise `10` to the integer power `$n: i32` in the specified generic type.\n\n\
+            Equivalent to `pow!(10, $n)`. Unlike `$t::from(10f64.powi($n)).unwrap()`, the power is \
+            computed in the target type, keeping large integer types exact.",
+            {
+                ($n: expr) => {
+                    $crate::_pow!($conv, $t, 10, $n)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] pow2,
+            "Raise `2` to the integer power `$n: i32` in the specified generic type.\n\n\
+            Equivalent to `pow!(2, $n)`. The multiplication overflows when the result does not fit, \
+            e.g. `pow2!(8)` for `u8`.",
+            {
+                ($n: expr) => {
+                    $crate::_pow!($conv, $t, 2, $n)
+                };
+            }
+        }
+    };
+    {@fallible [$t:tt; $conv:tt $($cfg:tt)*]} => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] try_num,
+            "Convert the expression into the specified generic type without unwrapping.\n\n\
+            Equivalent to `$t::from($n)`, returning `None` when the conversion fails \
+            instead of panicking.",
+            {
+                ($n: expr) => {
+                    $crate::_from!($conv, $t, $n)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] num_or,
+            "Convert the expression into the specified generic type, or use a fallback.\n\n\
+            Equivalent to `$t::from($n).unwrap_or($default)`.",
+            {
+                ($n: expr, $default: expr) => {
+                    $crate::_from!($conv, $t, $n).unwrap_or($default)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] num_checked,
+            "Convert the expression into the specified generic type, returning a typed error.\n\n\
+            Equivalent to `$t::from($n).ok_or(NumLazyError::OutOfRange)`, except that a `NaN` \
+            source gives `NumLazyError::NotRepresentable`. With `@via`, a source that does not \
+            implement `ToPrimitive` is never taken as `NaN`.",
+            {
+                ($n: expr) => {{
+                    let source = $n;
+                    let nan = ::core::matches!(
+                        $crate::_source_f64!(source),
+                        ::core::option::Option::Some(s) if s.is_nan()
+                    );
+                    match $crate::_from!($conv, $t, source) {
+                        ::core::option::Option::Some(value) => ::core::result::Result::Ok(value),
+                        ::core::option::Option::None if nan => {
+                            ::core::result::Result::Err($crate::NumLazyError::NotRepresentable)
+                        }
+                        ::core::option::Option::None => ::core::result::Result::Err($crate::NumLazyError::OutOfRange),
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] saturating_num,
+            "Convert the expression into the specified generic type, saturating at its bounds.\n\n\
+            When the conversion fails, return `$t::min_value()` for a negative source, \
+            `$t::max_value()` for a positive one, and `0` for `NaN`, like an `as` cast between \
+            primitive types. The sign is read with `ToPrimitive::to_f64`, so the source must \
+            implement `ToPrimitive` even with `@via`.",
+            {
+                ($n: expr) => {{
+                    let source = $n;
+                    let s = $crate::__num_traits::ToPrimitive::to_f64(&source).unwrap_or(::core::f64::NAN);
+                    match $crate::_from!($conv, $t, source) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => {
+                            if s < 0.0 {
+                                <$t>::min_value()
+                            } else if s > 0.0 {
+                                <$t>::max_value()
+                            } else {
+                                <$t as $crate::__num_traits::Zero>::zero()
+                            }
+                        }
+                    }
+                }};
+            }
+        }
+    };
+    {@literal [$t:tt; $conv:tt $($cfg:tt)*]} => {
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] zero, 0.0, "`0`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] one, 1.0, "`1`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] two, 2.0, "`2`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] three, 3.0, "`3`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] four, 4.0, "`4`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] five, 5.0, "`5`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] six, 6.0, "`6`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] seven, 7.0, "`7`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] eight, 8.0, "`8`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] nine, 9.0, "`9`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] ten, 10.0, "`10`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_one, -1.0, "`-1`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_two, -2.0, "`-2`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_three, -3.0, "`-3`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_four, -4.0, "`-4`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_five, -5.0, "`-5`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_six, -6.0, "`-6`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_seven, -7.0, "`-7`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_eight, -8.0, "`-8`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_nine, -9.0, "`-9`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] neg_ten, -10.0, "`-10`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] hundred, 100.0, "`100`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] thousand, 1e3, "`1e3`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] ten_thousand, 1e4, "`1e4`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] hundred_thousand, 1e5, "`1e5`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] million, 1e6, "`1e6`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] billion, 1e9, "`1e9`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] half, 0.5, "`0.5`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] third, 1.0/3.0, "`1/3`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] quarter, 0.25, "`0.25`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] two_thirds, 2.0/3.0, "`2/3`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] three_quarters, 0.75, "`0.75`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] five_sixths, 5.0/6.0, "`5/6`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] three_halves, 1.5, "`1.5`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] tenth, 0.1, "`0.1`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] hundredth, 0.01, "`0.01`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] thousandth, 1e-3, "`1e-3`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] millionth, 1e-6, "`1e-6`"}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] percent,
+            "A percentage of the specified generic type.\n\n\
+            Equivalent to `$t::from($x).unwrap() / hundred!()`, truncating for integer types.",
+            {
+                ($x: expr) => {
+                    $crate::_from!($conv, $t, $x).unwrap() / $crate::_from!($conv, $t, 100.0).unwrap()
+                };
+            }
+        }
+    };
+    {@constant $cfg:tt} => {
+        $crate::_declare_constant!{$cfg pi, PI, "π = `3.141592653589793`"}
+        $crate::_declare_constant!{$cfg pi_2, FRAC_PI_2, "π/2 = `1.5707963267948966`"}
+        $crate::_declare_constant!{$cfg pi_3, FRAC_PI_3, "π/3 = `1.0471975511965979`"}
+        $crate::_declare_constant!{$cfg pi_4, FRAC_PI_4, "π/4 = `0.7853981633974483`"}
+        $crate::_declare_constant!{$cfg pi_6, FRAC_PI_6, "π/6 = `0.5235987755982988`"}
+        $crate::_declare_literal!{$cfg three_pi_2, 4.71238898038469, "3π/2 = `4.71238898038469`"}
+        $crate::_declare_literal!{$cfg four_pi, 12.566370614359172, "4π = `12.566370614359172`"}
+        $crate::_declare_constant!{$cfg frac_1_pi, FRAC_1_PI, "1/π = `0.3183098861837907`"}
+        $crate::_declare_constant!{$cfg frac_2_pi, FRAC_2_PI, "2/π = `0.6366197723675814`"}
+        $crate::_declare_constant!{$cfg frac_2_sqrt_pi, FRAC_2_SQRT_PI, "2/sqrt(π) = `1.1283791670955126`"}
+        $crate::_declare_constant!{$cfg tau, TAU, "τ = 2π = `6.283185307179586`"}
+        $crate::_declare_constant!{$cfg e, E, "Euler's number (e) = `2.718281828459045`"}
+        $crate::_declare_constant!{$cfg ln_2, LN_2, "ln(2) = `0.6931471805599453`"}
+        $crate::_declare_constant!{$cfg ln_10, LN_10, "ln(10) = `2.302585092994046`"}
+        $crate::_declare_constant!{$cfg log2_10, LOG2_10, "log₂(10) = `3.321928094887362`"}
+        $crate::_declare_constant!{$cfg log2_e, LOG2_E, "log₂(e) = `1.4426950408889634`"}
+        $crate::_declare_constant!{$cfg log10_2, LOG10_2, "log₁₀(2) = `0.3010299956639812`"}
+        $crate::_declare_constant!{$cfg log10_e, LOG10_E, "log₁₀(e) = `0.4342944819032518`"}
+        $crate::_declare_constant!{$cfg sqrt_2, SQRT_2, "sqrt(2) = `1.4142135623730951`"}
+        $crate::_declare_constant!{$cfg frac_1_sqrt_2, FRAC_1_SQRT_2, "1/sqrt(2) = `0.7071067811865476`"}
+        $crate::_declare_literal!{$cfg sqrt_3, 1.7320508075688772, "sqrt(3) = `1.7320508075688772`"}
+        $crate::_declare_literal!{$cfg sqrt_5, 2.23606797749979, "sqrt(5) = `2.23606797749979`"}
+        $crate::_declare_literal!{$cfg sqrt_pi, 1.7724538509055159, "sqrt(π) = `1.7724538509055159`"}
+        $crate::_declare_literal!{$cfg sqrt_half_pi, 1.2533141373155003, "sqrt(π/2) = `1.2533141373155003`"}
+        $crate::_declare_literal!{$cfg gamma_half, 1.7724538509055159, "Γ(1/2) = sqrt(π) = `1.7724538509055159`, the same value as `sqrt_pi!()`"}
+        $crate::_declare_literal!{$cfg phi, 1.618033988749895, "The golden ratio (φ) = `1.618033988749895`"}
+        $crate::_declare_literal!{$cfg phi_conjugate, -0.6180339887498949, "The conjugate of the golden ratio (ψ) = (1 - sqrt(5))/2 = 1 - φ = -1/φ = `-0.6180339887498949`"}
+        $crate::_declare_literal!{$cfg silver_ratio, 2.414213562373095, "The silver ratio (δₛ) = 1 + sqrt(2) = `2.414213562373095`"}
+        $crate::_declare_literal!{$cfg frac_1_e, 0.36787944117144233, "1/e = `0.36787944117144233`"}
+        $crate::_declare_literal!{$cfg frac_1_tau, 0.15915494309189535, "1/τ = `0.15915494309189535`"}
+        $crate::_declare_literal!{$cfg frac_1_phi, 0.6180339887498949, "1/φ = φ - 1 = `0.6180339887498949`"}
+        $crate::_declare_literal!{$cfg euler_gamma, 0.5772156649015329, "The Euler–Mascheroni constant (γ) = `0.5772156649015329`"}
+        $crate::_declare_literal!{$cfg catalan, 0.915965594177219, "Catalan's constant (G) = `0.915965594177219`"}
+        $crate::_declare_literal!{$cfg apery, 1.2020569031595942, "Apéry's constant (ζ(3)) = `1.2020569031595942`"}
+        $crate::_declare_literal!{$cfg ln_pi, 1.1447298858494002, "ln(π) = `1.1447298858494002`"}
+        $crate::_declare_literal!{$cfg log10_pi, 0.4971498726941338, "log₁₀(π) = `0.4971498726941338`"}
+        $crate::_declare_literal!{$cfg ln_phi, 0.48121182505960347, "ln(φ) = `0.48121182505960347`"}
+        $crate::_declare_literal!{$cfg pi_squared, 9.869604401089358, "π² = `9.869604401089358`"}
+        $crate::_declare_literal!{$cfg e_squared, 7.38905609893065, "e² = `7.38905609893065`"}
+        $crate::_declare_literal!{$cfg tau_squared, 39.47841760435743, "τ² = `39.47841760435743`"}
+    };
+    {@special [$t:tt; $conv:tt $($cfg:tt)*]} => {
+        $crate::_declare_special!{[$t; $conv $($cfg)*] inf, infinity, "Infinity (`∞`)"}
+        $crate::_declare_special!{[$t; $conv $($cfg)*] neg_inf, neg_infinity, "Negative infinity (`-∞`)"}
+        $crate::_declare_special!{[$t; $conv $($cfg)*] nan, nan, "`NaN`"}
+        $crate::_declare_special!{[$t; $conv $($cfg)*] min_val, min_value, "The smallest finite value that this type can represent.\n- f32: `-3.4028235e38`\n- f64: `-1.7976931348623157e308`"}
+        $crate::_declare_special!{[$t; $conv $($cfg)*] max_val, max_value, "The largest finite value that this type can represent.\n- f32: `3.4028235e38`\n- f64: `1.7976931348623157e308`"}
+        $crate::_declare_special!{[$t; $conv $($cfg)*] min_positive, min_positive_value, "The smallest positive value that this type can represent.\n- f32: `1.1754944e-38`\n- f64: `2.2250738585072014e-308`"}
+        $crate::_declare_special!{[$t; $conv $($cfg)*] tiny, min_positive_value, "The smallest positive normal value, an alias of `min_positive!()`.\n- f32: `1.1754944e-38`\n- f64: `2.2250738585072014e-308`"}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] smallest_subnormal,
+            "The smallest positive subnormal value, computed as `min_positive!() * epsilon!()`.\n- f32: `1e-45`\n- f64: `5e-324`",
+            {
+                () => {
+                    <$t>::min_positive_value() * <$t>::epsilon()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] next_up,
+            "The smallest value of the specified generic type greater than `$x`.\n\n\
+            `next_up!(max_val!())` is `inf!()`, `inf!()` and `NaN` are returned unchanged, and \
+            `next_up!(neg_inf!())` is `min_val!()`. Both zeros step to `smallest_subnormal!()`.",
+            {
+                ($x: expr) => {
+                    $crate::_next_up!($t, $x)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] next_down,
+            "The largest value of the specified generic type less than `$x`.\n\n\
+            Equivalent to `-next_up!(-$x)`, so `next_down!(min_val!())` is `neg_inf!()`, \
+            `neg_inf!()` and `NaN` are returned unchanged, and `next_down!(inf!())` is `max_val!()`.",
+            {
+                ($x: expr) => {
+                    -$crate::_next_up!($t, -($x))
+                };
+            }
+        }
+        $crate::_declare_special!{[$t; $conv $($cfg)*] epsilon, epsilon, "`Machine epsilon` value for this type. This is the difference between `1.0` and the next larger representable number.\n- f32: `1.1920929e-7`\n- f64: `2.220446049250313e-16`"}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] half_epsilon,
+            "Half the machine epsilon, the unit roundoff of this type, computed as `epsilon!() / 2`.\n- f32: `5.9604645e-8`\n- f64: `1.1102230246251565e-16`",
+            {
+                () => {
+                    <$t>::epsilon() / (<$t as $crate::__num_traits::One>::one() + <$t as $crate::__num_traits::One>::one())
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] sqrt_epsilon,
+            "The square root of the machine epsilon, computed as `epsilon!().sqrt()`, for floats only.\n- f32: `0.00034526698`\n- f64: `1.4901161193847656e-8`",
+            {
+                () => {
+                    $crate::_float!($t, sqrt, <$t>::epsilon())
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] max_safe_int,
+            "The largest integer `n` such that every integer in `[-n, n]` is exactly representable, \
+            `2^mantissa_digits!()`, computed as `2 / epsilon!()`.\n- f32: `16777216` (2^24)\n- f64: `9007199254740992` (2^53)",
+            {
+                () => {
+                    (<$t as $crate::__num_traits::One>::one() + <$t as $crate::__num_traits::One>::one()) / <$t>::epsilon()
+                };
+            }
+        }
+        $crate::_declare_special!{[$t; $conv $($cfg)*] neg_zero, neg_zero, "`-0.0`"}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] is_finite,
+            "Whether a value of the specified generic type is neither infinite nor `NaN`.\n\n\
+            Equivalent to `$x.is_finite()`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    $crate::_float!($t, is_finite, x)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] is_nan,
+            "Whether a value of the specified generic type is `NaN`.\n\n\
+            Equivalent to `$x.is_nan()`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    $crate::_float!($t, is_nan, x)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] is_inf,
+            "Whether a value of the specified generic type is positive or negative infinity.\n\n\
+            Equivalent to `$x.is_infinite()`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    $crate::_float!($t, is_infinite, x)
+                }};
+            }
+        }
+        $crate::_declare_info!{[$t; $conv $($cfg)*] radix, radix, "The radix of the internal representation as `u32`, `2` for binary floating-point types."}
+        $crate::_declare_info!{[$t; $conv $($cfg)*] mantissa_digits, mantissa_digits, "The number of significant binary digits as `u32`, including the implicit bit.\n- f32: `24`\n- f64: `53`"}
+        $crate::_declare_info!{[$t; $conv $($cfg)*] digits, digits, "The approximate number of significant decimal digits as `u32`.\n- f32: `6`\n- f64: `15`"}
+        $crate::_declare_info!{[$t; $conv $($cfg)*] min_exp, min_exp, "One greater than the minimum normal power of 2 exponent as `i32`.\n- f32: `-125`\n- f64: `-1021`"}
+        $crate::_declare_info!{[$t; $conv $($cfg)*] max_exp, max_exp, "The maximum power of 2 exponent as `i32`.\n- f32: `128`\n- f64: `1024`"}
+    };
+    {@ops [$t:tt; $conv:tt $($cfg:tt)*] $d:tt} => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] recip,
+            "The reciprocal of a value of the specified generic type.\n\n\
+            Equivalent to `$t::one() / $x`.",
+            {
+                ($x: expr) => {
+                    <$t as $crate::__num_traits::One>::one() / ($x)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] square,
+            "The square of a value of the specified generic type.\n\n\
+            Equivalent to `$x * $x`, evaluating `$x` once.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    x * x
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] cube,
+            "The cube of a value of the specified generic type.\n\n\
+            Equivalent to `$x * $x * $x`, evaluating `$x` once.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    x * x * x
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] pow_n,
+            "Raise a value of the specified generic type to the power `$n: usize`.\n\n\
+            Equivalent to `num_traits::pow($x, $n)`, which squares and multiplies in the target \
+            type and only requires `Clone + One + Mul`, so it works for floats and integers alike.",
+            {
+                ($x: expr, $n: expr) => {{
+                    let x: $t = $x;
+                    $crate::__num_traits::pow(x, $n)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] powf,
+            "Raise a number to a power, both unwrapped into the specified generic type.\n\n\
+            Equivalent to `num!($base).powf(num!($exp))` of `Float`. Both operands are converted \
+            with `num!`, so literals such as `powf!(2, 0.5)` and values of the target type such as \
+            `powf!(four!(), half!())` both work, the latter unchanged.",
+            {
+                ($base: expr, $exp: expr) => {{
+                    let base: $t = $crate::_from!($conv, $t, $base).unwrap();
+                    let exp: $t = $crate::_from!($conv, $t, $exp).unwrap();
+                    $crate::_float!($t, powf, base, exp)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] fma,
+            "Fused multiply-add of three values of the specified generic type.\n\n\
+            Equivalent to `Float::mul_add($a, $b, $c)`, computing `$a * $b + $c` with a single \
+            rounding. Without the `libm` feature, integers implementing `MulAdd` also work and \
+            compute plain `$a * $b + $c`.",
+            {
+                ($a: expr, $b: expr, $c: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    let c: $t = $c;
+                    $crate::_float!($t, mul_add, a, b, c)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] lerp,
+            "Linear interpolation between two values of the specified generic type.\n\n\
+            Equivalent to `$a + ($b - $a) * $x`, evaluating `$a` once.",
+            {
+                ($a: expr, $b: expr, $x: expr) => {{
+                    let a: $t = $a;
+                    a + (($b) - a) * ($x)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] midpoint,
+            "The midpoint of two values of the specified generic type.\n\n\
+            Equivalent to `($a + $b) / 2`, which is `($a + $b) * 0.5` for floats.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let two: $t = <$t as $crate::__num_traits::One>::one() + <$t as $crate::__num_traits::One>::one();
+                    (($a) + ($b)) / two
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] copysign_one,
+            "One with the sign of a value of the specified generic type.\n\n\
+            Equivalent to `$t::one().copysign($x)`, so `-0.0` and a `NaN` with the sign bit set \
+            give `-1`.",
+            {
+                ($x: expr) => {
+                    $crate::_float!($t, copysign, <$t as $crate::__num_traits::One>::one(), $x)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] signum,
+            "The sign of a value of the specified generic type, as given by its `signum` method.\n\n\
+            Resolves to `Float::signum` or `Signed::signum`, whichever is in scope. For floats, \
+            `0.0` gives `1`, `-0.0` gives `-1`, and `NaN` gives `NaN`. For signed integers, `0` \
+            gives `0`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    x.signum()
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] sign_of,
+            "The sign of a value of the specified generic type as `-1`, `0`, or `1`.\n\n\
+            Zero (including `-0.0`) and `NaN` are returned unchanged.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    let zero = <$t as $crate::__num_traits::Zero>::zero();
+                    let one = <$t as $crate::__num_traits::One>::one();
+                    if x > zero {
+                        one
+                    } else if x < zero {
+                        zero - one
+                    } else {
+                        x
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] is_positive,
+            "Whether a value of the specified generic type is greater than zero.\n\n\
+            Equivalent to `$x > $t::zero()`, so `0.0`, `-0.0`, and `NaN` are not positive.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    x > <$t as $crate::__num_traits::Zero>::zero()
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] is_negative,
+            "Whether a value of the specified generic type is less than zero.\n\n\
+            Equivalent to `$x < $t::zero()`, so `-0.0` and `NaN` are not negative regardless of \
+            their sign bit.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    x < <$t as $crate::__num_traits::Zero>::zero()
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] is_zero,
+            "Whether a value of the specified generic type is zero.\n\n\
+            Equivalent to `$x.is_zero()` of `num_traits::Zero`, which is `true` for both `0.0` and \
+            `-0.0` and `false` for `NaN`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    <$t as $crate::__num_traits::Zero>::is_zero(&x)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] horner,
+            "Evaluate a polynomial at a value of the specified generic type with Horner's scheme.\n\n\
+            `horner!($x; c0, c1, c2, ...)` is `c0 + $x * (c1 + $x * (c2 + ...))`, with each \
+            coefficient unwrapped into the target type and `$x` evaluated once.",
+            {
+                ($x: expr; $d($d c: expr),+ $d(,)?) => {{
+                    let x: $t = $x;
+                    $crate::_horner!($conv, $t, x; $d($d c),+)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] rem_euclid,
+            "The Euclidean remainder of two values of the specified generic type.\n\n\
+            Equivalent to `num_traits::Euclid::rem_euclid(&$a, &$b)`, which is never negative, so \
+            `rem_euclid!(-1, 3)` is `2` where `-1 % 3` is `-1`. Requires `$t: Euclid`.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    <$t as $crate::__num_traits::Euclid>::rem_euclid(&a, &b)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] div_euclid,
+            "The Euclidean quotient of two values of the specified generic type.\n\n\
+            Equivalent to `num_traits::Euclid::div_euclid(&$a, &$b)`, rounding so that \
+            `$a == $b * div_euclid!($a, $b) + rem_euclid!($a, $b)`. Requires `$t: Euclid`.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    <$t as $crate::__num_traits::Euclid>::div_euclid(&a, &b)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] checked_div,
+            "Divide two values of the specified generic type, returning `None` for a zero divisor.\n\n\
+            Equivalent to `if is_zero!($b) { None } else { Some($a / $b) }`, also giving `None` \
+            for `min_val!() / -1` of signed integers, which overflows. Float division by zero gives \
+            `inf` or `NaN` and integer division by zero panics, so this handles both the same way. \
+            A `NaN` divisor is not zero and still gives `Some(NaN)`.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    // `-a` only leaves the range of `$t` for the minimum of a signed integer type.
+                    let overflow = $crate::__num_traits::ToPrimitive::to_i128(&b)
+                        == ::core::option::Option::Some(-1)
+                        && ::core::matches!(
+                            $crate::__num_traits::ToPrimitive::to_i128(&a),
+                            ::core::option::Option::Some(a) if a
+                                .checked_neg()
+                                .and_then(<$t as $crate::__num_traits::NumCast>::from)
+                                .is_none()
+                        );
+                    if <$t as $crate::__num_traits::Zero>::is_zero(&b) || overflow {
+                        ::core::option::Option::None
+                    } else {
+                        ::core::option::Option::Some(a / b)
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] sat_add,
+            "Add two integers of the specified generic type, saturating at its bounds.\n\n\
+            Equivalent to `$a.checked_add(&$b)`, or `$t::min_value()` or `$t::max_value()` in the \
+            direction of the overflow. Requires `CheckedAdd + Bounded`, both implied by `PrimInt`.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    match <$t as $crate::__num_traits::CheckedAdd>::checked_add(&a, &b) {
+                        ::core::option::Option::Some(r) => r,
+                        ::core::option::Option::None if b < <$t as $crate::__num_traits::Zero>::zero() => {
+                            <$t as $crate::__num_traits::Bounded>::min_value()
+                        }
+                        ::core::option::Option::None => <$t as $crate::__num_traits::Bounded>::max_value(),
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] sat_mul,
+            "Multiply two integers of the specified generic type, saturating at its bounds.\n\n\
+            Equivalent to `$a.checked_mul(&$b)`, or `$t::min_value()` or `$t::max_value()` by the \
+            sign of the exact product. Requires `CheckedMul + Bounded`, both implied by `PrimInt`.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    let zero = <$t as $crate::__num_traits::Zero>::zero();
+                    match <$t as $crate::__num_traits::CheckedMul>::checked_mul(&a, &b) {
+                        ::core::option::Option::Some(r) => r,
+                        ::core::option::Option::None if (a < zero) != (b < zero) => {
+                            <$t as $crate::__num_traits::Bounded>::min_value()
+                        }
+                        ::core::option::Option::None => <$t as $crate::__num_traits::Bounded>::max_value(),
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] max_of,
+            "The largest of one or more values of the specified generic type.\n\n\
+            Compares with `PartialOrd`. Like `Float::max`, `NaN` is ignored unless every value is `NaN`.",
+            {
+                ($x: expr $d(, $d rest: expr)* $d(,)?) => {{
+                    let mut m: $t = $x;
+                    $d(
+                        let r: $t = $d rest;
+                        if r > m || m != m {
+                            m = r;
+                        }
+                    )*
+                    m
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] min_of,
+            "The smallest of one or more values of the specified generic type.\n\n\
+            Compares with `PartialOrd`. Like `Float::min`, `NaN` is ignored unless every value is `NaN`.",
+            {
+                ($x: expr $d(, $d rest: expr)* $d(,)?) => {{
+                    let mut m: $t = $x;
+                    $d(
+                        let r: $t = $d rest;
+                        if r < m || m != m {
+                            m = r;
+                        }
+                    )*
+                    m
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] abs_diff,
+            "The absolute difference of two values of the specified generic type.\n\n\
+            Equivalent to `($a - $b).abs()`, but computed as `$a - $b` or `$b - $a` with `PartialOrd`, \
+            so it needs no `abs` method and works for unsigned integers.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    if a > b {
+                        a - b
+                    } else {
+                        b - a
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] clamp_to_unit,
+            "Clamp a value of the specified generic type into `[0, 1]`.\n\n\
+            The comparison uses `PartialOrd`, so a `NaN` input is returned unchanged.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    let zero = <$t as $crate::__num_traits::Zero>::zero();
+                    let one = <$t as $crate::__num_traits::One>::one();
+                    if x < zero {
+                        zero
+                    } else if x > one {
+                        one
+                    } else {
+                        x
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] clamp_nonneg,
+            "Clamp a value of the specified generic type to be nonnegative.\n\n\
+            Equivalent to `max($x, 0)` with `PartialOrd`, so a `NaN` input is returned unchanged \
+            rather than `0` as with `Float::max`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    let zero = <$t as $crate::__num_traits::Zero>::zero();
+                    if x < zero {
+                        zero
+                    } else {
+                        x
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] clamp_nonpos,
+            "Clamp a value of the specified generic type to be nonpositive.\n\n\
+            Equivalent to `min($x, 0)` with `PartialOrd`, so a `NaN` input is returned unchanged \
+            rather than `0` as with `Float::min`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    let zero = <$t as $crate::__num_traits::Zero>::zero();
+                    if x > zero {
+                        zero
+                    } else {
+                        x
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] wrap_unit,
+            "Wrap a value of the specified generic type into `[0, 1)`, for floats only.\n\n\
+            Equivalent to `$x - $x.floor()`, so negative inputs wrap around from `1`, e.g. `-0.25` \
+            becomes `0.75`, and exact integers become `0`. A tiny negative input whose result would \
+            round up to `1` also gives `0`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    let one = <$t as $crate::__num_traits::One>::one();
+                    let r = x - $crate::_float!($t, floor, x);
+                    if r >= one {
+                        r - one
+                    } else {
+                        r
+                    }
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] round_to,
+            "Round a value of the specified generic type to `$digits: i32` decimal places.\n\n\
+            Equivalent to `($x * pow10!($digits)).round() / pow10!($digits)`. Halfway cases round away from \
+            zero as with `Float::round`, not to even.",
+            {
+                ($x: expr, $digits: expr) => {{
+                    let x: $t = $x;
+                    let scale: $t = $crate::_pow!($conv, $t, 10, $digits);
+                    $crate::_float!($t, round, x * scale) / scale
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] linspace_step,
+            "The step between `$n` evenly spaced values of the specified generic type from `$start` \
+            to `$stop`.\n\n\
+            Equivalent to `($stop - $start) / ($n - 1)`, with `$n` converted into the target type. \
+            `$n == 1` divides by zero, giving an infinity or `NaN` for floats and panicking for \
+            integers.",
+            {
+                ($start: expr, $stop: expr, $n: expr) => {{
+                    let start: $t = $start;
+                    let stop: $t = $stop;
+                    let n: $t = $crate::_from!($conv, $t, $n).unwrap();
+                    (stop - start) / (n - <$t as $crate::__num_traits::One>::one())
+                }};
+            }
+        }
+    };
+    {@integer $cfg:tt} => {
+        $crate::_declare_literal!{$cfg zero, 0, "`0`"}
+        $crate::_declare_literal!{$cfg one, 1, "`1`"}
+        $crate::_declare_literal!{$cfg two, 2, "`2`"}
+        $crate::_declare_literal!{$cfg three, 3, "`3`"}
+        $crate::_declare_literal!{$cfg four, 4, "`4`"}
+        $crate::_declare_literal!{$cfg five, 5, "`5`"}
+        $crate::_declare_literal!{$cfg six, 6, "`6`"}
+        $crate::_declare_literal!{$cfg seven, 7, "`7`"}
+        $crate::_declare_literal!{$cfg eight, 8, "`8`"}
+        $crate::_declare_literal!{$cfg nine, 9, "`9`"}
+        $crate::_declare_literal!{$cfg ten, 10, "`10`"}
+        $crate::_declare_literal!{$cfg hundred, 100, "`100`"}
+        $crate::_declare_literal!{$cfg thousand, 1_000, "`1_000`"}
+        $crate::_declare_literal!{$cfg million, 1_000_000, "`1_000_000`"}
+    };
+    {@angle [$t:tt; $conv:tt $($cfg:tt)*]} => {
+        $crate::_declare_constant!{[$t; $conv $($cfg)*] deg_30, FRAC_PI_6, "30° = π/6 = `0.5235987755982988` rad"}
+        $crate::_declare_constant!{[$t; $conv $($cfg)*] deg_45, FRAC_PI_4, "45° = π/4 = `0.7853981633974483` rad"}
+        $crate::_declare_constant!{[$t; $conv $($cfg)*] deg_60, FRAC_PI_3, "60° = π/3 = `1.0471975511965979` rad"}
+        $crate::_declare_constant!{[$t; $conv $($cfg)*] deg_90, FRAC_PI_2, "90° = π/2 = `1.5707963267948966` rad"}
+        $crate::_declare_constant!{[$t; $conv $($cfg)*] deg_180, PI, "180° = π = `3.141592653589793` rad"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] deg_270, 4.71238898038469, "270° = 3π/2 = `4.71238898038469` rad"}
+        $crate::_declare_constant!{[$t; $conv $($cfg)*] deg_360, TAU, "360° = 2π = `6.283185307179586` rad"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] deg_per_rad, 57.29577951308232, "180/π = `57.29577951308232` degrees per radian"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] rad_per_deg, 0.017453292519943295, "π/180 = `0.017453292519943295` radians per degree"}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] deg_to_rad,
+            "Convert an angle of the specified generic type from degrees to radians.\n\n\
+            Equivalent to `$x * $t::from(π/180).unwrap()`.",
+            {
+                ($x: expr) => {
+                    ($x) * $crate::_from!($conv, $t, 0.017453292519943295).unwrap()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] rad_to_deg,
+            "Convert an angle of the specified generic type from radians to degrees.\n\n\
+            Equivalent to `$x * $t::from(180/π).unwrap()`.",
+            {
+                ($x: expr) => {
+                    ($x) * $crate::_from!($conv, $t, 57.29577951308232).unwrap()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] to_degrees,
+            "Convert an angle of the specified generic type from radians to degrees with `Float`.\n\n\
+            Equivalent to `$x.to_degrees()` of `num_traits::Float`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    $crate::_float!($t, to_degrees, x)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] to_radians,
+            "Convert an angle of the specified generic type from degrees to radians with `Float`.\n\n\
+            Equivalent to `$x.to_radians()` of `num_traits::Float`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    $crate::_float!($t, to_radians, x)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] deg,
+            "Convert a number of degrees into radians of the specified generic type.\n\n\
+            Equivalent to `$t::from($x).unwrap() * $t::from(π).unwrap() / $t::from(180).unwrap()`.",
+            {
+                ($x: expr) => {
+                    $crate::_from!($conv, $t, $x).unwrap() * $crate::_from!($conv, $t, ::core::f64::consts::PI).unwrap()
+                        / $crate::_from!($conv, $t, 180).unwrap()
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] wrap_angle,
+            "Wrap an angle of the specified generic type in radians into `[-π, π)`.\n\n\
+            Equivalent to `$x - τ * (($x + π) / τ).floor()`, so `π` wraps to `-π` while `-π` is \
+            unchanged.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    let pi: $t = $crate::_from!($conv, $t, ::core::f64::consts::PI).unwrap();
+                    let tau: $t = $crate::_from!($conv, $t, ::core::f64::consts::TAU).unwrap();
+                    let r = x - tau * $crate::_float!($t, floor, (x + pi) / tau);
+                    if r >= pi {
+                        r - tau
+                    } else if r < -pi {
+                        r + tau
+                    } else {
+                        r
+                    }
+                }};
+            }
+        }
+    };
+    {@trig $cfg:tt} => {
+        $crate::_declare_literal!{$cfg sin_30, 0.5, "sin(30°) = `0.5`"}
+        $crate::_declare_literal!{$cfg cos_30, 0.8660254037844386, "cos(30°) = sqrt(3)/2 = `0.8660254037844386`"}
+        $crate::_declare_literal!{$cfg tan_30, 0.5773502691896257, "tan(30°) = 1/sqrt(3) = `0.5773502691896257`"}
+        $crate::_declare_constant!{$cfg sin_45, FRAC_1_SQRT_2, "sin(45°) = sqrt(2)/2 = `0.7071067811865476`"}
+        $crate::_declare_constant!{$cfg cos_45, FRAC_1_SQRT_2, "cos(45°) = sqrt(2)/2 = `0.7071067811865476`"}
+        $crate::_declare_literal!{$cfg tan_45, 1.0, "tan(45°) = `1`"}
+        $crate::_declare_literal!{$cfg sin_60, 0.8660254037844386, "sin(60°) = sqrt(3)/2 = `0.8660254037844386`"}
+        $crate::_declare_literal!{$cfg cos_60, 0.5, "cos(60°) = `0.5`"}
+        $crate::_declare_literal!{$cfg tan_60, 1.7320508075688772, "tan(60°) = sqrt(3) = `1.7320508075688772`"}
+    };
+    {@stats [$t:tt; $conv:tt $($cfg:tt)*] $d:tt} => {
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] sqrt_2pi, 2.5066282746310002, "sqrt(2π) = `2.5066282746310002`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] frac_1_sqrt_2pi, 0.3989422804014327, "1/sqrt(2π) = `0.3989422804014327`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] ln_2pi, 1.8378770664093453, "ln(2π) = `1.8378770664093453`"}
+        $crate::_declare_literal!{[$t; $conv $($cfg)*] half_ln_2pi, 0.9189385332046727, "ln(2π)/2 = ln(sqrt(2π)) = `0.9189385332046727`, the constant term of Stirling's approximation of ln Γ"}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] mean,
+            "The arithmetic mean of one or more values of the specified generic type.\n\n\
+            Equivalent to `($a + $b + ...) / $t::from(count).unwrap()`. The sum is accumulated in `T`, so many \
+            large values can overflow (to infinity for floats) before dividing, and integer types \
+            truncate the result.",
+            {
+                ($x: expr $d(, $d rest: expr)* $d(,)?) => {{
+                    let mut sum: $t = $x;
+                    let mut count: usize = 1;
+                    $d(
+                        let r: $t = $d rest;
+                        sum = sum + r;
+                        count += 1;
+                    )*
+                    // The count is a `usize`, not a source for a `@via` conversion function.
+                    sum / $crate::_from!((), $t, count).unwrap()
+                }};
+            }
+        }
+    };
+    {@physics $cfg:tt} => {
+        $crate::_declare_literal!{$cfg speed_of_light, 299792458.0, "Speed of light in vacuum (c) = `299792458` m/s"}
+        $crate::_declare_literal!{$cfg planck, 6.62607015e-34, "Planck constant (h) = `6.62607015e-34` J⋅s"}
+        $crate::_declare_literal!{$cfg grav_const, 6.67430e-11, "Newtonian constant of gravitation (G) = `6.67430e-11` m³/(kg⋅s²)"}
+        $crate::_declare_literal!{$cfg boltzmann, 1.380649e-23, "Boltzmann constant (k) = `1.380649e-23` J/K"}
+        $crate::_declare_literal!{$cfg avogadro, 6.02214076e23, "Avogadro constant (Nₐ) = `6.02214076e23` mol⁻¹"}
+        $crate::_declare_literal!{$cfg elementary_charge, 1.602176634e-19, "Elementary charge (e) = `1.602176634e-19` C"}
+    };
+    {@astro $cfg:tt} => {
+        $crate::_declare_literal!{$cfg au, 1.495978707e11, "Astronomical unit (au) = `1.495978707e11` m"}
+        $crate::_declare_literal!{$cfg julian_year, 3.15576e7, "Julian year = `3.15576e7` s"}
+        $crate::_declare_literal!{$cfg solar_mass, 1.98847e30, "Solar mass (M☉) = `1.98847e30` kg"}
+        $crate::_declare_literal!{$cfg standard_gravity, 9.80665, "Standard acceleration of gravity (g₀) = `9.80665` m/s²"}
+    };
+    {@thermo $cfg:tt} => {
+        $crate::_declare_literal!{$cfg gas_constant, 8.314462618, "Molar gas constant (R) = `8.314462618` J/(mol⋅K)"}
+        $crate::_declare_literal!{$cfg stefan_boltzmann, 5.670374419e-8, "Stefan–Boltzmann constant (σ) = `5.670374419e-8` W/(m²⋅K⁴)"}
+        $crate::_declare_literal!{$cfg standard_atm, 101325.0, "Standard atmosphere (atm) = `101325` Pa"}
+        $crate::_declare_literal!{$cfg absolute_zero_c, -273.15, "Absolute zero = `-273.15` °C"}
+    };
+    {@em $cfg:tt} => {
+        $crate::_declare_literal!{$cfg epsilon_0, 8.8541878128e-12, "Vacuum electric permittivity (ε₀) = `8.8541878128e-12` F/m"}
+        $crate::_declare_literal!{$cfg mu_0, 1.25663706212e-6, "Vacuum magnetic permeability (μ₀) = `1.25663706212e-6` N/A²"}
+        $crate::_declare_literal!{$cfg impedance_free_space, 376.730313668, "Characteristic impedance of vacuum (Z₀) = `376.730313668` Ω"}
+    };
+    {@approx [$t:tt; $conv:tt $($cfg:tt)*]} => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] close_to,
+            "Whether two values of the specified generic type are approximately equal.\n\n\
+            Equivalent to `(a - b).abs() <= tol * a.abs().max(b.abs()).max(1)`, where `tol` is \
+            `$t::epsilon()` or the optional third argument. `NaN` is never close to anything.",
+            {
+                ($a: expr, $b: expr) => {
+                    $crate::_close_to!($t, $a, $b, $crate::_float!($t, epsilon))
+                };
+                ($a: expr, $b: expr, $tol: expr) => {
+                    $crate::_close_to!($t, $a, $b, $tol)
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] is_negligible,
+            "Whether a value of the specified generic type is smaller in magnitude than `epsilon!()`.\n\n\
+            Equivalent to `$x.abs() < $t::epsilon()`.",
+            {
+                ($x: expr) => {{
+                    let x: $t = $x;
+                    $crate::_float!($t, abs, x) < $crate::_float!($t, epsilon)
+                }};
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] nan_safe_eq,
+            "Whether two values of the specified generic type are equal, treating `NaN` as equal to `NaN`.\n\n\
+            Equivalent to `a == b || (a.is_nan() && b.is_nan())`. Unlike IEEE 754 equality, two `NaN`s \
+            compare equal regardless of payload, while `0.0` and `-0.0` still compare equal.",
+            {
+                ($a: expr, $b: expr) => {{
+                    let a: $t = $a;
+                    let b: $t = $b;
+                    a == b || (a != a && b != b)
+                }};
+            }
+        }
+    };
+    {@parse [$t:tt; $conv:tt $($cfg:tt)*]} => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] from_str_num,
+            "Parse a string into the specified generic type, returning a `Result`.\n\n\
+            Equivalent to `$t::from_str_radix($s, 10)` through `num_traits::Num`.",
+            {
+                ($s: expr) => {
+                    <$t as $crate::__num_traits::Num>::from_str_radix($s, 10)
+                };
+            }
+        }
+    };
+}
+
+/// Declare the `@complex` macros for `num_complex::Complex<$t>`.
+#[cfg(feature = "num-complex")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_complex {
+    ([$t:tt; $conv:tt $($cfg:tt)*]) => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] complex,
+            "Construct a `Complex<$t>` from the real and imaginary parts.\n\n\
+            Equivalent to `Complex::new($t::from($re).unwrap(), $t::from($im).unwrap())`.",
+            {
+                ($re: expr, $im: expr) => {
+                    $crate::__num_complex::Complex::<$t>::new(
+                        $crate::_from!($conv, $t, $re).unwrap(),
+                        $crate::_from!($conv, $t, $im).unwrap(),
+                    )
+                };
+            }
+        }
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] i, "The imaginary unit `0 + 1i`", {
+            () => {
+                $crate::__num_complex::Complex::<$t>::new(
+                    <$t as $crate::__num_traits::Zero>::zero(),
+                    <$t as $crate::__num_traits::One>::one(),
+                )
+            };
+        }}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] complex_zero, "`0 + 0i`", {
+            () => {
+                <$crate::__num_complex::Complex<$t> as $crate::__num_traits::Zero>::zero()
+            };
+        }}
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] complex_one, "`1 + 0i`", {
+            () => {
+                <$crate::__num_complex::Complex<$t> as $crate::__num_traits::One>::one()
+            };
+        }}
+    };
+}
+
+#[cfg(not(feature = "num-complex"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_complex {
+    ($($tt:tt)*) => {
+        ::core::compile_error!(
+            "`declare_nums!{@complex T}` requires the `num-complex` feature of num-lazy"
+        );
+    };
+}
+
+/// Declare a literal macro expanding to `$t::from($n).unwrap()`.
+///
+/// For `f32` and `f64`, expand to a cast through `f64`, giving the same value in `const` contexts.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_literal {
+    ([f32; () $($cfg:tt)*] $name:ident, $n:expr, $doc:expr) => {
+        $crate::_declare_macro!{[f32; () $($cfg)*] $name, $doc, {
+            () => {
+                ($n) as f64 as f32
+            };
+        }}
+    };
+    ([f64; () $($cfg:tt)*] $name:ident, $n:expr, $doc:expr) => {
+        $crate::_declare_macro!{[f64; () $($cfg)*] $name, $doc, {
+            () => {
+                ($n) as f64
+            };
+        }}
+    };
+    ([$t:tt; $conv:tt $($cfg:tt)*] $name:ident, $n:expr, $doc:expr) => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] $name, $doc, {
+            () => {
+                $crate::_from!($conv, $t, $n).unwrap()
+            };
+        }}
+    };
+}
+
+/// Declare a constant macro expanding to `$t::from(core::f64::consts::$constant).unwrap()`.
+///
+/// For `f32` and `f64`, expand to the constant of `core::f32::consts` or `core::f64::consts` so
+/// it can be used in `const` contexts.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_constant {
+    ([f32; () $($cfg:tt)*] $name:ident, $constant:ident, $doc:expr) => {
+        $crate::_declare_macro!{[f32; () $($cfg)*] $name, $doc, {
+            () => {
+                ::core::f32::consts::$constant
+            };
+        }}
+    };
+    ([f64; () $($cfg:tt)*] $name:ident, $constant:ident, $doc:expr) => {
+        $crate::_declare_macro!{[f64; () $($cfg)*] $name, $doc, {
+            () => {
+                ::core::f64::consts::$constant
+            };
+        }}
+    };
+    ([$t:tt; $conv:tt $($cfg:tt)*] $name:ident, $constant:ident, $doc:expr) => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] $name, $doc, {
+            () => {
+                $crate::_from!($conv, $t, ::core::f64::consts::$constant).unwrap()
+            };
+        }}
+    };
+}
+
+/// Declare a special macro expanding to `$t::$const_fn()`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_special {
+    ([$t:tt; $conv:tt $($cfg:tt)*] $name:ident, $const_fn:ident, $doc:expr) => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] $name, $doc, {
+            () => {
+                <$t>::$const_fn()
             };
+        }}
+    };
+}
+
+/// Convert `$n` into `Option<$t>` through `num_traits::NumCast`, or `num_traits::FromPrimitive`
+/// if `$t` is not known to implement `NumCast`.
+///
+/// The conversion is selected by autoref: [`__ViaNumCast`] is implemented for `__Target<T>`, so
+/// `(&target).__convert(n)` finds it without an extra reference, while [`__ViaFromPrimitive`] is
+/// implemented for `&__Target<T>` and only found when `T: NumCast` does not hold. This also works with a generic `T` bounded by either trait.
+///
+/// With a conversion function `($conv)` from `declare_nums!{@via}`, call it instead and turn its
+/// `Option` or `Result` into an `Option`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _from {
+    ((), $t:ty, $n:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__ViaFromPrimitive as _, __ViaNumCast as _};
+        (&$crate::__Target::<$t>(::core::marker::PhantomData)).__convert($n)
+    }};
+    (($conv:path), $t:ty, $n:expr) => {
+        $crate::__IntoOption::<$t>::__into_option($conv($n))
+    };
+}
+
+/// Convert `$n` into `$t` for `num!`, panicking with the stringified expression and the type name.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _num {
+    ($conv:tt, $t:ty, $n:expr) => {
+        $crate::_from!($conv, $t, $n).unwrap_or_else(|| {
+            ::core::panic!(
+                "num!({}) cannot be converted into `{}`",
+                ::core::stringify!($n),
+                ::core::any::type_name::<$t>(),
+            )
+        })
+    };
+}
+
+/// A raw bit pattern for `num_bits!` declared for `f32` or `f64`, converted by `const` methods so
+/// that both targets accept a `u32` or a `u64` like [`__FromBits`] does for other types.
+#[doc(hidden)]
+pub struct __Bits<B>(pub B);
+
+impl __Bits<u32> {
+    #[inline]
+    pub const fn __to_f32(self) -> f32 {
+        f32::from_bits(self.0)
+    }
+
+    #[inline]
+    pub const fn __to_f64(self) -> f64 {
+        f32::from_bits(self.0) as f64
+    }
+}
+
+impl __Bits<u64> {
+    #[inline]
+    pub const fn __to_f32(self) -> f32 {
+        f64::from_bits(self.0) as f32
+    }
+
+    #[inline]
+    pub const fn __to_f64(self) -> f64 {
+        f64::from_bits(self.0)
+    }
+}
+
+/// The float format of a raw bit pattern for `num_bits!`: `u32` for `f32` and `u64` for `f64`.
+#[doc(hidden)]
+pub trait __FromBits {
+    type Float;
+    fn __from_bits(self) -> Self::Float;
+}
+
+impl __FromBits for u32 {
+    type Float = f32;
+    #[inline]
+    fn __from_bits(self) -> f32 {
+        f32::from_bits(self)
+    }
+}
+
+impl __FromBits for u64 {
+    type Float = f64;
+    #[inline]
+    fn __from_bits(self) -> f64 {
+        f64::from_bits(self)
+    }
+}
+
+/// Whether `names` contains `name`, usable in the const checks of `except[...]` and `only[...]`.
+#[doc(hidden)]
+pub const fn __contains(names: &[&str], name: &str) -> bool {
+    let name = name.as_bytes();
+    let mut i = 0;
+    while i < names.len() {
+        let n = names[i].as_bytes();
+        if n.len() == name.len() {
+            let mut j = 0;
+            while j < n.len() && n[j] == name[j] {
+                j += 1;
+            }
+            if j == n.len() {
+                return true;
+            }
         }
+        i += 1;
+    }
+    false
+}
 
-        _declare_literal! { zero, 0.0, "`0`"}
-        _declare_literal! { one, 1.0, "`1`"}
-        _declare_literal! { two, 2.0, "`2`"}
-        _declare_literal! { three, 3.0, "`3`"}
-        _declare_literal! { four, 4.0, "`4`"}
-        _declare_literal! { five, 5.0, "`5`"}
-        _declare_literal! { six, 6.0, "`6`"}
-        _declare_literal! { seven, 7.0, "`7`"}
-        _declare_literal! { eight, 8.0, "`8`"}
-        _declare_literal! { nine, 9.0, "`9`"}
-        _declare_literal! { ten, 10.0, "`10`"}
-        _declare_literal! { hundred, 100.0, "`100`"}
-        _declare_literal! { thousand, 1e3, "`1e3`"}
-        _declare_literal! { million, 1e6, "`1e6`"}
-        _declare_literal! { half, 0.5, "`0.5`"}
-        _declare_literal! { third, 1.0/3.0, "`1/3`"}
-        _declare_literal! { quarter, 0.25, "`0.25`"}
-        _declare_literal! { tenth, 0.1, "`0.1`"}
-        _declare_literal! { hundredth, 0.01, "`0.01`"}
-        _declare_literal! { thousandth, 1e-3, "`1e-3`"}
-        _declare_literal! { millionth, 1e-6, "`1e-6`"}
-    };
-    (@constant $t:ident) => {
-        macro_rules! _declare_constant {
-            ($name:ident, $constant:ident, $doc:expr) => {
-                #[allow(unused_macros)]
-                #[doc=$doc]
-                macro_rules! $name {
-                    () => {
-                        $t::from(core::f64::consts::$constant).unwrap()
-                    };
-                }
+/// The source `$n` as `Option<f64>` through `num_traits::ToPrimitive`, or `None` if its type is not
+/// known to implement `ToPrimitive`, such as the source of a `declare_nums!{@via}` function.
+///
+/// The method is selected by autoref: [`__SourceToPrimitive`] is implemented for `__Source<N>`, so
+/// `(&source).__to_f64()` finds it without an extra reference, while [`__SourceOpaque`] is
+/// implemented for `&__Source<N>` and is only found when `N: ToPrimitive` does not hold.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _source_f64 {
+    ($n:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::{__SourceOpaque as _, __SourceToPrimitive as _};
+        (&$crate::__Source(&$n)).__to_f64()
+    }};
+}
+
+#[doc(hidden)]
+pub struct __Source<'a, N>(pub &'a N);
+
+#[doc(hidden)]
+pub trait __SourceToPrimitive {
+    fn __to_f64(&self) -> Option<f64>;
+}
+
+impl<N: num_traits::ToPrimitive> __SourceToPrimitive for __Source<'_, N> {
+    #[inline]
+    fn __to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+#[doc(hidden)]
+pub trait __SourceOpaque {
+    fn __to_f64(&self) -> Option<f64>;
+}
+
+impl<N> __SourceOpaque for &__Source<'_, N> {
+    #[inline]
+    fn __to_f64(&self) -> Option<f64> {
+        None
+    }
+}
+
+/// The `Option` or `Result` returned by a conversion function given to `declare_nums!{@via}`.
+#[doc(hidden)]
+pub trait __IntoOption<T> {
+    fn __into_option(self) -> Option<T>;
+}
+
+impl<T> __IntoOption<T> for Option<T> {
+    #[inline]
+    fn __into_option(self) -> Option<T> {
+        self
+    }
+}
+
+impl<T, E> __IntoOption<T> for Result<T, E> {
+    #[inline]
+    fn __into_option(self) -> Option<T> {
+        self.ok()
+    }
+}
+
+#[doc(hidden)]
+pub struct __Target<T>(pub core::marker::PhantomData<T>);
+
+#[doc(hidden)]
+pub trait __ViaNumCast<T> {
+    fn __convert<N: num_traits::ToPrimitive>(&self, n: N) -> Option<T>;
+}
+
+impl<T: num_traits::NumCast> __ViaNumCast<T> for __Target<T> {
+    #[inline]
+    fn __convert<N: num_traits::ToPrimitive>(&self, n: N) -> Option<T> {
+        T::from(n)
+    }
+}
+
+#[doc(hidden)]
+pub trait __ViaFromPrimitive<T> {
+    fn __convert<N: num_traits::ToPrimitive>(&self, n: N) -> Option<T>;
+}
+
+impl<T: num_traits::FromPrimitive> __ViaFromPrimitive<T> for &__Target<T> {
+    /// Integral values go through `from_i64` or `from_u64` to stay exact, the rest through `from_f64`.
+    #[inline]
+    fn __convert<N: num_traits::ToPrimitive>(&self, n: N) -> Option<T> {
+        let f = n.to_f64();
+        match (n.to_i64(), n.to_u64()) {
+            (Some(i), _) if f == Some(i as f64) => T::from_i64(i),
+            (_, Some(u)) if f == Some(u as f64) => T::from_u64(u),
+            _ => f.and_then(T::from_f64),
+        }
+    }
+}
+
+/// Declare a macro expanding to a floating-point characteristic of `$t`, see [`_float_info`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_info {
+    ([$t:tt; $conv:tt $($cfg:tt)*] $name:ident, $info:ident, $doc:expr) => {
+        $crate::_declare_macro!{[$t; $conv $($cfg)*] $name, $doc, {
+            () => {
+                $crate::_float_info!($t, $info)
             };
+        }}
+    };
+}
+
+/// Compute the floating-point characteristics of `$t` with `integer_decode`.
+///
+/// `integer_decode` has no fixed normalization (`half` decodes through `f32`), so only
+/// `floor(log2(x)) = bits(mantissa) - 1 + exponent` is used. For binary floats with `p` significant
+/// digits, `epsilon = 2^(1-p)`, `min_positive = 2^(min_exp-1)`, and `max_value < 2^max_exp`. The
+/// decimal digits are `floor((p-1) * log10(2))` with `log10(2) ≈ 0.30103`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _float_info {
+    ($t:ty, radix) => {
+        2_u32
+    };
+    ($t:ty, mantissa_digits) => {
+        (1 - $crate::_float_info!(@log2 <$t>::epsilon())) as u32
+    };
+    ($t:ty, digits) => {
+        ($crate::_float_info!($t, mantissa_digits) - 1) * 30103 / 100000
+    };
+    ($t:ty, min_exp) => {
+        $crate::_float_info!(@log2 <$t>::min_positive_value()) + 1
+    };
+    ($t:ty, max_exp) => {
+        $crate::_float_info!(@log2 <$t>::max_value()) + 1
+    };
+    (@log2 $x:expr) => {{
+        let (mantissa, exp, _) = $x.integer_decode();
+        63 - mantissa.leading_zeros() as i32 + exp as i32
+    }};
+}
+
+/// Convert the raw bits `$bits` into `$t`, with `from_bits` for `f32` and `f64`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _num_bits {
+    (f32, (), $bits:expr) => {
+        $crate::__Bits($bits).__to_f32()
+    };
+    (f64, (), $bits:expr) => {
+        $crate::__Bits($bits).__to_f64()
+    };
+    ($t:ty, $conv:tt, $bits:expr) => {
+        $crate::_from!($conv, $t, $crate::__FromBits::__from_bits($bits)).unwrap()
+    };
+}
+
+/// Build a `Vec<$t>` from the unwrapped expressions.
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _num_vec {
+    ($conv:tt, $t:ty; $($n:expr),*) => {{
+        let v: $crate::__alloc::vec::Vec<$t> = $crate::__alloc::vec![$($crate::_from!($conv, $t, $n).unwrap()),*];
+        v
+    }};
+}
+
+#[cfg(not(feature = "alloc"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _num_vec {
+    ($($tt:tt)*) => {
+        ::core::compile_error!("`num_vec!` requires the `alloc` feature of num-lazy")
+    };
+}
+
+/// Declare every macro for `$t` as `declare_nums!{$t}` does, without `#[allow(unused_macros)]`
+/// and with each name spanned at `$t`, so rustc reports the unused ones.
+#[cfg(feature = "macros")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_strict {
+    ($t:ident) => {
+        $crate::_declare_nums! {@all [$t; () () () (@strict) ()]}
+    };
+}
+
+#[cfg(not(feature = "macros"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_strict {
+    ($($tt:tt)*) => {
+        ::core::compile_error!(
+            "`declare_nums!{@strict T}` requires the `macros` feature of num-lazy"
+        );
+    };
+}
+
+/// Convert `$n` into `$t` and log the source and result with `log::trace!`. The source is
+/// formatted before it is moved into the conversion, so it does not need to be `Copy`.
+#[cfg(feature = "log")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _debug_num {
+    ($conv:tt, $t:ty, $n:expr) => {{
+        let source = $n;
+        let shown = if $crate::__log::log_enabled!($crate::__log::Level::Trace) {
+            ::core::option::Option::Some($crate::__alloc::format!("{:?}", source))
+        } else {
+            ::core::option::Option::None
+        };
+        let value: $t = $crate::_from!($conv, $t, source).unwrap();
+        if let ::core::option::Option::Some(shown) = shown {
+            $crate::__log::trace!(
+                "debug_num!({}): {} into `{}` = {:?}",
+                ::core::stringify!($n),
+                shown,
+                ::core::any::type_name::<$t>(),
+                value,
+            );
         }
-        _declare_constant! { pi, PI, "π = `3.141592653589793`"}
-        _declare_constant! { pi_2, FRAC_PI_2, "π/2 = `1.5707963267948966`"}
-        _declare_constant! { pi_3, FRAC_PI_3, "π/3 = `1.0471975511965979`"}
-        _declare_constant! { frac_1_pi, FRAC_1_PI, "1/π = `0.3183098861837907`"}
-        _declare_constant! { frac_2_pi, FRAC_2_PI, "2/π = `0.6366197723675814`"}
-        _declare_constant! { frac_2_sqrt_pi, FRAC_2_SQRT_PI, "2/sqrt(π) = `1.1283791670955126`"}
-        _declare_constant! { tau, TAU, "τ = 2π = `6.283185307179586`"}
-        _declare_constant! { e, E, "Euler's number (e) = `2.718281828459045`"}
-        _declare_constant! { ln_2, LN_2, "ln(2) = `0.6931471805599453`"}
-        _declare_constant! { ln_10, LN_10, "ln(10) = `2.302585092994046`"}
-        _declare_constant! { log2_10, LOG2_10, "log₂(10) = `3.321928094887362`"}
-        _declare_constant! { log2_e, LOG2_E, "log₂(e) = `1.4426950408889634`"}
-        _declare_constant! { log10_2, LOG10_2, "log₁₀(2) = `0.3010299956639812`"}
-        _declare_constant! { log10_e, LOG10_E, "log₁₀(e) = `0.4342944819032518`"}
-        _declare_constant! { sqrt_2, SQRT_2, "sqrt(2) = `1.4142135623730951`"}
-        _declare_constant! { frac_1_sqrt_2, FRAC_1_SQRT_2, "1/sqrt(2) = `0.7071067811865476`"}
-        _declare_constant! { phi, PHI, "The golden ratio (φ) = `1.618033988749895`"}
-    };
-    (@special $t:ident) => {
-        macro_rules! _declare_special {
-            ($name:ident, $const_fn:ident, $doc:expr) => {
-                #[allow(unused_macros)]
-                #[doc=$doc]
-                macro_rules! $name {
-                    () => {
-                        $t::$const_fn()
-                    };
-                }
+        value
+    }};
+}
+
+#[cfg(not(feature = "log"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _debug_num {
+    ($($tt:tt)*) => {
+        ::core::compile_error!("`debug_num!` requires the `log` feature of num-lazy")
+    };
+}
+
+/// Call a `Float` method of `$t`, through `num_traits::Float` (and so `libm`) with the `libm`
+/// feature.
+#[cfg(feature = "libm")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _float {
+    ($t:ty, $method:ident) => {
+        <$t as $crate::__num_traits::Float>::$method()
+    };
+    ($t:ty, $method:ident, $x:expr $(, $arg:expr)*) => {
+        <$t as $crate::__num_traits::Float>::$method($x $(, $arg)*)
+    };
+}
+
+#[cfg(not(feature = "libm"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _float {
+    ($t:ty, $method:ident) => {
+        <$t>::$method()
+    };
+    ($t:ty, $method:ident, $x:expr $(, $arg:expr)*) => {
+        ($x).$method($($arg),*)
+    };
+}
+
+/// Step `$x` to the next representable value of `$t` towards positive infinity.
+///
+/// The gap above a positive normal `a` in the binade `[2^k, 2^(k+1))` is `epsilon * 2^k`, or
+/// `smallest_subnormal!()` for subnormals. `k` comes from the same `floor(log2(a))` identity as
+/// `_float_info!`, which does not depend on the width of the `integer_decode` mantissa (`half`
+/// decodes through `f32`). Stepping a negative value towards zero uses the gap below its
+/// magnitude, which is halved at powers of two.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _next_up {
+    ($t:ty, $x:expr) => {{
+        let x: $t = $x;
+        let zero = <$t as $crate::__num_traits::Zero>::zero();
+        let tiny = <$t>::min_positive_value();
+        if x.is_nan() || x == <$t>::infinity() {
+            x
+        } else if x == <$t>::neg_infinity() {
+            <$t>::min_value()
+        } else if x == zero {
+            tiny * <$t>::epsilon()
+        } else {
+            let a = x.abs();
+            let two = <$t as $crate::__num_traits::One>::one() + <$t as $crate::__num_traits::One>::one();
+            let binade = if a < tiny {
+                tiny
+            } else {
+                two.powi($crate::_float_info!(@log2 a))
             };
+            let gap = binade * <$t>::epsilon();
+            if x > zero {
+                x + gap
+            } else if a <= tiny || a != binade {
+                let r = x + gap;
+                if r == zero {
+                    <$t>::neg_zero()
+                } else {
+                    r
+                }
+            } else {
+                x + gap / two
+            }
+        }
+    }};
+}
+
+/// Compare `$a` and `$b` in `$t` with a tolerance relative to the larger magnitude or `1`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _close_to {
+    ($t:ty, $a:expr, $b:expr, $tol:expr) => {{
+        let a: $t = $a;
+        let b: $t = $b;
+        let tol: $t = $tol;
+        let one = <$t as $crate::__num_traits::One>::one();
+        let scale = $crate::_float!(
+            $t,
+            max,
+            $crate::_float!(
+                $t,
+                max,
+                $crate::_float!($t, abs, a),
+                $crate::_float!($t, abs, b)
+            ),
+            one
+        );
+        $crate::_float!($t, abs, a - b) <= tol * scale
+    }};
+}
+
+/// Expand `c0 + $x * (c1 + $x * (...))` over the coefficients, unwrapping each into `$t`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _horner {
+    ($conv:tt, $t:ty, $x:ident; $c:expr) => {
+        $crate::_from!($conv, $t, $c).unwrap()
+    };
+    ($conv:tt, $t:ty, $x:ident; $c:expr, $($rest:expr),+) => {
+        $crate::_from!($conv, $t, $c).unwrap() + $x * $crate::_horner!($conv, $t, $x; $($rest),+)
+    };
+}
+
+/// Compute `$base^$exp` in `$t` by exponentiation by squaring.
+///
+/// A negative exponent divides `1` by `$base^n` in chunks, halving a chunk whose power overflows
+/// (`inf * 0` is not `0`), so a result near the subnormal range is not lost to an infinite
+/// intermediate.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _pow {
+    ($conv:tt, $t:ty, $base:expr, $exp:expr) => {{
+        let base: $t = $crate::_from!($conv, $t, $base).unwrap();
+        let exp: i32 = $exp;
+        let one: $t = $crate::_from!($conv, $t, 1).unwrap();
+        let zero: $t = $crate::_from!($conv, $t, 0).unwrap();
+        let power = |mut n: u32| {
+            let mut result = one;
+            let mut square = base;
+            while n > 0 {
+                if n & 1 == 1 {
+                    result = result * square;
+                }
+                n >>= 1;
+                if n > 0 {
+                    square = square * square;
+                }
+            }
+            result
+        };
+        if exp >= 0 {
+            power(exp.unsigned_abs())
+        } else {
+            let mut n = exp.unsigned_abs();
+            let mut chunk = n;
+            let mut result = one;
+            while n > 0 {
+                let step = chunk.min(n);
+                let p = power(step);
+                if chunk > 1 && p * zero != zero {
+                    chunk /= 2;
+                    continue;
+                }
+                result = result / p;
+                n -= step;
+            }
+            result
+        }
+    }};
+}
+
+/// Declare `const` arrays listing the macro names of each group, for `declare_nums!{@names T}`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_names {
+    () => {
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@num T}`."]
+        const NUM_LAZY_NUMS: &[&str] = &[
+            "num",
+            "nums",
+            "filled",
+            "num_vec",
+            "debug_num",
+            "num_type_name",
+            "num_bits",
+            "from_bool",
+            "bool_true",
+            "bool_false",
+            "frac",
+            "from_ratio",
+            "mul_num",
+            "clamp_num",
+            "pow",
+            "pow10",
+            "pow2",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@fallible T}`."]
+        const NUM_LAZY_FALLIBLE: &[&str] = &["try_num", "num_or", "num_checked", "saturating_num"];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@literal T}`."]
+        const NUM_LAZY_LITERALS: &[&str] = &[
+            "zero",
+            "one",
+            "two",
+            "three",
+            "four",
+            "five",
+            "six",
+            "seven",
+            "eight",
+            "nine",
+            "ten",
+            "neg_one",
+            "neg_two",
+            "neg_three",
+            "neg_four",
+            "neg_five",
+            "neg_six",
+            "neg_seven",
+            "neg_eight",
+            "neg_nine",
+            "neg_ten",
+            "hundred",
+            "thousand",
+            "ten_thousand",
+            "hundred_thousand",
+            "million",
+            "billion",
+            "half",
+            "third",
+            "quarter",
+            "two_thirds",
+            "three_quarters",
+            "five_sixths",
+            "three_halves",
+            "tenth",
+            "hundredth",
+            "thousandth",
+            "millionth",
+            "percent",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@constant T}`."]
+        const NUM_LAZY_CONSTANTS: &[&str] = &[
+            "pi",
+            "pi_2",
+            "pi_3",
+            "pi_4",
+            "pi_6",
+            "three_pi_2",
+            "four_pi",
+            "frac_1_pi",
+            "frac_2_pi",
+            "frac_2_sqrt_pi",
+            "tau",
+            "e",
+            "ln_2",
+            "ln_10",
+            "log2_10",
+            "log2_e",
+            "log10_2",
+            "log10_e",
+            "sqrt_2",
+            "frac_1_sqrt_2",
+            "sqrt_3",
+            "sqrt_5",
+            "sqrt_pi",
+            "sqrt_half_pi",
+            "gamma_half",
+            "phi",
+            "phi_conjugate",
+            "silver_ratio",
+            "frac_1_e",
+            "frac_1_tau",
+            "frac_1_phi",
+            "euler_gamma",
+            "catalan",
+            "apery",
+            "ln_pi",
+            "log10_pi",
+            "ln_phi",
+            "pi_squared",
+            "e_squared",
+            "tau_squared",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@special T}`."]
+        const NUM_LAZY_SPECIALS: &[&str] = &[
+            "inf",
+            "neg_inf",
+            "nan",
+            "min_val",
+            "max_val",
+            "min_positive",
+            "tiny",
+            "smallest_subnormal",
+            "next_up",
+            "next_down",
+            "epsilon",
+            "half_epsilon",
+            "sqrt_epsilon",
+            "max_safe_int",
+            "neg_zero",
+            "is_finite",
+            "is_nan",
+            "is_inf",
+            "radix",
+            "mantissa_digits",
+            "digits",
+            "min_exp",
+            "max_exp",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@ops T}`."]
+        const NUM_LAZY_OPS: &[&str] = &[
+            "recip",
+            "square",
+            "cube",
+            "pow_n",
+            "powf",
+            "fma",
+            "lerp",
+            "midpoint",
+            "copysign_one",
+            "signum",
+            "sign_of",
+            "is_positive",
+            "is_negative",
+            "is_zero",
+            "horner",
+            "rem_euclid",
+            "div_euclid",
+            "checked_div",
+            "sat_add",
+            "sat_mul",
+            "max_of",
+            "min_of",
+            "abs_diff",
+            "clamp_to_unit",
+            "clamp_nonneg",
+            "clamp_nonpos",
+            "wrap_unit",
+            "round_to",
+            "linspace_step",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@integer T}`."]
+        const NUM_LAZY_INTEGERS: &[&str] = &[
+            "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine", "ten",
+            "hundred", "thousand", "million",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@angle T}`."]
+        const NUM_LAZY_ANGLES: &[&str] = &[
+            "deg_30",
+            "deg_45",
+            "deg_60",
+            "deg_90",
+            "deg_180",
+            "deg_270",
+            "deg_360",
+            "deg_per_rad",
+            "rad_per_deg",
+            "deg_to_rad",
+            "rad_to_deg",
+            "to_degrees",
+            "to_radians",
+            "deg",
+            "wrap_angle",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@trig T}`."]
+        const NUM_LAZY_TRIG: &[&str] = &[
+            "sin_30", "cos_30", "tan_30", "sin_45", "cos_45", "tan_45", "sin_60", "cos_60",
+            "tan_60",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@stats T}`."]
+        const NUM_LAZY_STATS: &[&str] = &[
+            "sqrt_2pi",
+            "frac_1_sqrt_2pi",
+            "ln_2pi",
+            "half_ln_2pi",
+            "mean",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@physics T}`."]
+        const NUM_LAZY_PHYSICS: &[&str] = &[
+            "speed_of_light",
+            "planck",
+            "grav_const",
+            "boltzmann",
+            "avogadro",
+            "elementary_charge",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@astro T}`."]
+        const NUM_LAZY_ASTRO: &[&str] = &["au", "julian_year", "solar_mass", "standard_gravity"];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@thermo T}`."]
+        const NUM_LAZY_THERMO: &[&str] = &[
+            "gas_constant",
+            "stefan_boltzmann",
+            "standard_atm",
+            "absolute_zero_c",
+        ];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@em T}`."]
+        const NUM_LAZY_EM: &[&str] = &["epsilon_0", "mu_0", "impedance_free_space"];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@approx T}`."]
+        const NUM_LAZY_APPROX: &[&str] = &["close_to", "is_negligible", "nan_safe_eq"];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@parse T}`."]
+        const NUM_LAZY_PARSE: &[&str] = &["from_str_num"];
+        #[allow(dead_code)]
+        #[doc = "The macros of `declare_nums!{@complex T}`."]
+        const NUM_LAZY_COMPLEX: &[&str] = &["complex", "i", "complex_zero", "complex_one"];
+    };
+}
+
+/// Check that each listed name is declared by `declare_nums!{T}`, against the `_declare_names!`
+/// lists of its groups, so a misspelled name in `except[...]` or `only[...]` fails to compile.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _check_names {
+    ($form:literal [$($x:ident),+]) => {
+        const _: () = {
+            $crate::_declare_names!{}
+            $(::core::assert!(
+                $crate::__contains(NUM_LAZY_NUMS, ::core::stringify!($x))
+                    || $crate::__contains(NUM_LAZY_FALLIBLE, ::core::stringify!($x))
+                    || $crate::__contains(NUM_LAZY_LITERALS, ::core::stringify!($x))
+                    || $crate::__contains(NUM_LAZY_CONSTANTS, ::core::stringify!($x))
+                    || $crate::__contains(NUM_LAZY_SPECIALS, ::core::stringify!($x))
+                    || $crate::__contains(NUM_LAZY_OPS, ::core::stringify!($x)),
+                ::core::concat!("`", ::core::stringify!($x), "` in `", $form, "[...]` is not a name declared by `declare_nums!`")
+            );)+
+        };
+    };
+}
+
+/// Define a filter macro dropping the excluded names and populate the module through it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_except {
+    ([$t:tt; $conv:tt $($cfg:tt)*] [$($x:ident),+] $d:tt) => {
+        $crate::_check_names!{"except" [$($x),+]}
+        $crate::__paste! {
+            macro_rules! [<_num_lazy_except $(_ $x)+>] {
+                $(($x $d($d tt:tt)*) => {};)+
+                ($d name:ident $d($d tt:tt)*) => {
+                    $d($d tt)*
+                };
+            }
+            $crate::_declare_nums!{@all [$t; $conv $($cfg)* ([<_num_lazy_except $(_ $x)+>])]}
+        }
+    };
+}
+
+/// Define a filter macro keeping only the listed names and populate the module through it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_only {
+    ([$t:tt; $conv:tt $($cfg:tt)*] [$($x:ident),+] $d:tt) => {
+        $crate::_check_names!{"only" [$($x),+]}
+        $crate::__paste! {
+            macro_rules! [<_num_lazy_only $(_ $x)+>] {
+                $(($x $d($d tt:tt)*) => {
+                    $d($d tt)*
+                };)+
+                ($d name:ident $d($d tt:tt)*) => {};
+            }
+            $crate::_declare_nums!{@all [$t; $conv $($cfg)* ([<_num_lazy_only $(_ $x)+>])]}
+        }
+    };
+}
+
+/// Emit a single `macro_rules!` definition, pasting the configured prefix and suffix onto its name.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! _declare_macro {
+    ([$t:tt; $conv:tt $p:tt $s:tt $vis:tt ($filter:ident)] $name:ident, $doc:expr, $rules:tt) => {
+        $filter!{$name $crate::_declare_macro!{[$t; $conv $p $s $vis ()] $name, $doc, $rules}}
+    };
+    ([$t:tt; $conv:tt ($($p:tt)*) ($($s:tt)*) () ()] $name:ident, $doc:expr, $rules:tt) => {
+        $crate::__paste! {
+            #[allow(unused_macros)]
+            #[doc = $doc]
+            macro_rules! [<$($p)* $name $($s)*>] $rules
+        }
+    };
+    ([$t:tt; $conv:tt ($($p:tt)*) ($($s:tt)*) (#[macro_export]) ()] $name:ident, $doc:expr, $rules:tt) => {
+        $crate::__paste! {
+            #[allow(unused_macros)]
+            #[doc = $doc]
+            #[macro_export]
+            macro_rules! [<$($p)* $name $($s)*>] $rules
+        }
+    };
+    ([$t:tt; $conv:tt ($($p:tt)*) ($($s:tt)*) (@strict) ()] $name:ident, $doc:expr, $rules:tt) => {
+        $crate::__paste! {
+            $crate::__strict_macro! {$t
+                #[doc = $doc]
+                macro_rules! [<$($p)* $name $($s)*>] $rules
+            }
+        }
+    };
+    ([$t:tt; $conv:tt ($($p:tt)*) ($($s:tt)*) ($vis:vis) ()] $name:ident, $doc:expr, $rules:tt) => {
+        $crate::_declare_macro!{[$t; $conv ($($p)*) ($($s)*) () ()] $name, $doc, $rules}
+        $crate::__paste! {
+            #[allow(unused_imports)]
+            $vis use [<$($p)* $name $($s)*>];
         }
-        _declare_special! { inf, infinity, "Infinity (`∞`)"}
-        _declare_special! { neg_inf, neg_infinity, "Negative infinity (`-∞`)"}
-        _declare_special! { nan, nan, "`NaN`"}
-        _declare_special! { min_val, min_value, "The smallest finite value that this type can represent.\n- f32: `-3.4028235e38`\n- f64: `-1.7976931348623157e308`"}
-        _declare_special! { max_val, max_value, "The largest finite value that this type can represent.\n- f32: `3.4028235e38`\n- f64: `1.7976931348623157e308`"}
-        _declare_special! { min_positive, min_positive_value, "The smallest positive value that this type can represent.\n- f32: `1.1754944e-38`\n- f64: `2.2250738585072014e-308`"}
-        _declare_special! { epsilon, epsilon, "`Machine epsilon` value for this type. This is the difference between `1.0` and the next larger representable number.\n- f32: `1.1920929e-7`\n- f64: `2.220446049250313e-16`"}
-        _declare_special! { neg_zero, neg_zero, "`-0.0`"}
     };
 }
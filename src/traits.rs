@@ -0,0 +1,125 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+/*! Trait-based alternative to the macros, enabled with the `traits` feature.
+
+```
+use num_lazy::traits::NumLazy;
+
+fn circumference<T: NumLazy>(radius: T) -> T {
+    T::two() * T::pi() * radius
+}
+#
+# fn main() {
+#     assert!(circumference(1.0_f64) == 6.283185307179586);
+# }
+```
+
+[NumLazy] is implemented for every type implementing `num_traits::Float`. The `traits` feature
+enables the `libm` feature of `num-traits`, so `Float` is available in `no_std` crates.
+*/
+
+use core::f64::consts::*;
+use num_traits::{Float, NumCast};
+
+macro_rules! lazy_fns {
+    ($($name:ident, $n:expr, $doc:expr;)*) => {
+        $(
+            #[doc = $doc]
+            #[inline]
+            fn $name() -> Self {
+                <Self as NumCast>::from($n).unwrap()
+            }
+        )*
+    };
+}
+
+/// The literals and constants of `declare_nums!{@literal T}` and `declare_nums!{@constant T}`
+/// as associated functions, e.g. `T::pi()` for `pi!()`.
+///
+/// `zero` and `one`, as well as the values of `declare_nums!{@special T}`, are already provided
+/// by `Float` (`T::zero()`, `T::infinity()`, `T::epsilon()`, etc.) and are not repeated here.
+pub trait NumLazy: Float {
+    lazy_fns! {
+        two, 2.0, "`2`";
+        three, 3.0, "`3`";
+        four, 4.0, "`4`";
+        five, 5.0, "`5`";
+        six, 6.0, "`6`";
+        seven, 7.0, "`7`";
+        eight, 8.0, "`8`";
+        nine, 9.0, "`9`";
+        ten, 10.0, "`10`";
+        neg_one, -1.0, "`-1`";
+        neg_two, -2.0, "`-2`";
+        neg_three, -3.0, "`-3`";
+        neg_four, -4.0, "`-4`";
+        neg_five, -5.0, "`-5`";
+        neg_six, -6.0, "`-6`";
+        neg_seven, -7.0, "`-7`";
+        neg_eight, -8.0, "`-8`";
+        neg_nine, -9.0, "`-9`";
+        neg_ten, -10.0, "`-10`";
+        hundred, 100.0, "`100`";
+        thousand, 1e3, "`1e3`";
+        ten_thousand, 1e4, "`1e4`";
+        hundred_thousand, 1e5, "`1e5`";
+        million, 1e6, "`1e6`";
+        billion, 1e9, "`1e9`";
+        half, 0.5, "`0.5`";
+        third, 1.0/3.0, "`1/3`";
+        quarter, 0.25, "`0.25`";
+        two_thirds, 2.0/3.0, "`2/3`";
+        three_quarters, 0.75, "`0.75`";
+        five_sixths, 5.0/6.0, "`5/6`";
+        three_halves, 1.5, "`1.5`";
+        tenth, 0.1, "`0.1`";
+        hundredth, 0.01, "`0.01`";
+        thousandth, 1e-3, "`1e-3`";
+        millionth, 1e-6, "`1e-6`";
+        pi, PI, "π = `3.141592653589793`";
+        pi_2, FRAC_PI_2, "π/2 = `1.5707963267948966`";
+        pi_3, FRAC_PI_3, "π/3 = `1.0471975511965979`";
+        pi_4, FRAC_PI_4, "π/4 = `0.7853981633974483`";
+        pi_6, FRAC_PI_6, "π/6 = `0.5235987755982988`";
+        three_pi_2, 4.71238898038469, "3π/2 = `4.71238898038469`";
+        four_pi, 12.566370614359172, "4π = `12.566370614359172`";
+        frac_1_pi, FRAC_1_PI, "1/π = `0.3183098861837907`";
+        frac_2_pi, FRAC_2_PI, "2/π = `0.6366197723675814`";
+        frac_2_sqrt_pi, FRAC_2_SQRT_PI, "2/sqrt(π) = `1.1283791670955126`";
+        tau, TAU, "τ = 2π = `6.283185307179586`";
+        e, E, "Euler's number (e) = `2.718281828459045`";
+        ln_2, LN_2, "ln(2) = `0.6931471805599453`";
+        ln_10, LN_10, "ln(10) = `2.302585092994046`";
+        log2_10, LOG2_10, "log₂(10) = `3.321928094887362`";
+        log2_e, LOG2_E, "log₂(e) = `1.4426950408889634`";
+        log10_2, LOG10_2, "log₁₀(2) = `0.3010299956639812`";
+        log10_e, LOG10_E, "log₁₀(e) = `0.4342944819032518`";
+        sqrt_2, SQRT_2, "sqrt(2) = `1.4142135623730951`";
+        frac_1_sqrt_2, FRAC_1_SQRT_2, "1/sqrt(2) = `0.7071067811865476`";
+        sqrt_3, 1.7320508075688772, "sqrt(3) = `1.7320508075688772`";
+        sqrt_5, 2.23606797749979, "sqrt(5) = `2.23606797749979`";
+        sqrt_pi, 1.7724538509055159, "sqrt(π) = `1.7724538509055159`";
+        sqrt_half_pi, 1.2533141373155003, "sqrt(π/2) = `1.2533141373155003`";
+        gamma_half, 1.7724538509055159, "Γ(1/2) = sqrt(π) = `1.7724538509055159`, the same value as `sqrt_pi`";
+        phi, 1.618033988749895, "The golden ratio (φ) = `1.618033988749895`";
+        phi_conjugate, -0.6180339887498949, "The conjugate of the golden ratio (ψ) = 1 - φ = `-0.6180339887498949`";
+        silver_ratio, 2.414213562373095, "The silver ratio (δₛ) = 1 + sqrt(2) = `2.414213562373095`";
+        frac_1_e, 0.36787944117144233, "1/e = `0.36787944117144233`";
+        frac_1_tau, 0.15915494309189535, "1/τ = `0.15915494309189535`";
+        frac_1_phi, 0.6180339887498949, "1/φ = φ - 1 = `0.6180339887498949`";
+        euler_gamma, 0.5772156649015329, "The Euler–Mascheroni constant (γ) = `0.5772156649015329`";
+        catalan, 0.915965594177219, "Catalan's constant (G) = `0.915965594177219`";
+        apery, 1.2020569031595942, "Apéry's constant (ζ(3)) = `1.2020569031595942`";
+        ln_pi, 1.1447298858494002, "ln(π) = `1.1447298858494002`";
+        log10_pi, 0.4971498726941338, "log₁₀(π) = `0.4971498726941338`";
+        ln_phi, 0.48121182505960347, "ln(φ) = `0.48121182505960347`";
+        pi_squared, 9.869604401089358, "π² = `9.869604401089358`";
+        e_squared, 7.38905609893065, "e² = `7.38905609893065`";
+        tau_squared, 39.47841760435743, "τ² = `39.47841760435743`";
+    }
+}
+
+impl<T: Float> NumLazy for T {}
@@ -0,0 +1,21 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Helpers shared by the integration tests, included with `mod common;`.
+
+#![allow(dead_code)]
+
+use num_traits::Float;
+
+/// Assert that `a` and `b` are within one epsilon of each other, relative to the larger one.
+pub fn assert_close<T: Float>(a: T, b: T) {
+    assert_close_ulps(a, b, 1);
+}
+
+/// Assert that `a` and `b` are within `ulps` epsilons of each other, relative to the larger one.
+pub fn assert_close_ulps<T: Float>(a: T, b: T, ulps: u8) {
+    let tol = T::epsilon() * T::from(ulps).unwrap();
+    assert!((a - b).abs() <= tol * a.abs().max(b.abs()));
+}
@@ -3,7 +3,7 @@
  * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
  */
 
-use num_lazy::declare_nums;
+use num_lazy::{declare_nums, NumLazyError};
 use num_traits::{Float, PrimInt};
 
 declare_nums! {T}
@@ -25,6 +25,75 @@ fn test_num() {
     int_function::<i64>();
 }
 
+#[test]
+fn test_num_tuple() {
+    fn float_function<T: Float>() -> (T, T) {
+        let (x, y, z): (T, T, T) = num!(1.0, 2.0, 3.0);
+        assert!(x + y == z);
+        assert!(num!(0.5, 1,) == (half!(), one!()));
+        num!(1.0, 2.0)
+    }
+
+    fn int_function<T: PrimInt>() -> (T, T) {
+        num!(4, 2)
+    }
+
+    assert!(float_function::<f64>() == (1.0, 2.0));
+    assert!(float_function::<f32>() == (1.0, 2.0));
+    assert!(int_function::<u8>() == (4, 2));
+}
+
+#[test]
+#[should_panic(expected = "num!(-1) cannot be converted into `u8`")]
+fn test_num_tuple_panic() {
+    fn int_function<T: PrimInt>() -> (T, T) {
+        num!(1, -1)
+    }
+
+    int_function::<u8>();
+}
+
+#[test]
+fn test_nums() {
+    fn float_function<T: Float>() {
+        let array: [T; 3] = nums![1, 2, 3];
+        assert!(array == [one!(), two!(), three!()]);
+        assert!(nums![0.5,] == [half!()]);
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    fn int_function<T: PrimInt>() -> [T; 2] {
+        nums![4, 2]
+    }
+
+    assert!(int_function::<i32>() == [4, 2]);
+}
+
+#[test]
+fn test_filled() {
+    fn float_function<T: Float>() {
+        let array: [T; 4] = filled!(0.5; 4);
+        assert!(array == [half!(); 4]);
+    }
+
+    fn const_function<T: Float, const N: usize>() -> [T; N] {
+        filled!(1; N)
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(const_function::<f64, 3>() == [1.0; 3]);
+
+    fn int_function<T: PrimInt>() -> [T; 2] {
+        filled!(7; 2)
+    }
+
+    assert!(int_function::<u8>() == [7, 7]);
+}
+
 #[test]
 fn test_consts() {
     fn float_function<T: Float>() {
@@ -37,3 +106,461 @@ fn test_consts() {
     float_function::<f64>();
     float_function::<f32>();
 }
+
+#[test]
+fn test_try_num() {
+    fn float_function<T: Float>() -> Option<T> {
+        try_num!(42.42)
+    }
+
+    fn int_function<T: PrimInt>() -> Option<T> {
+        try_num!(300)
+    }
+
+    assert!(float_function::<f64>() == Some(42.42));
+    assert!(int_function::<u8>().is_none());
+    assert!(int_function::<i32>() == Some(300));
+}
+
+#[test]
+fn test_frac() {
+    fn float_function<T: Float>() {
+        assert!(frac!(1, 3) == T::one() / T::from(3).unwrap());
+        assert!(frac!(2, 7) == T::from(2).unwrap() / T::from(7).unwrap());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        frac!(1, 3)
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 0);
+    assert!(int_function::<i64>() == 0);
+}
+
+#[test]
+fn test_from_bool() {
+    fn float_function<T: Float>() {
+        assert!(from_bool!(true) == one!());
+        assert!(from_bool!(false) == zero!());
+        assert!(from_bool!(two!() > one!()) == bool_true!());
+        assert!(bool_false!() == zero!());
+    }
+
+    fn int_function<T: PrimInt>(flags: [bool; 3]) -> T {
+        flags.iter().fold(zero!(), |n, &b| n + from_bool!(b))
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>([true, false, true]) == 2);
+    assert!(int_function::<u8>([false; 3]) == 0);
+}
+
+#[test]
+fn test_from_ratio() {
+    fn float_function<T: Float>() {
+        assert!(from_ratio!(1, 3) == third!());
+        assert!(from_ratio!(2, 3) == two_thirds!());
+        assert!(from_ratio!(1, 3) == frac!(1, 3));
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        from_ratio!(7, 2)
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 3);
+}
+
+#[test]
+fn test_mul_num() {
+    fn float_function<T: Float>() {
+        assert!(mul_num!(one!(), 1000) == thousand!());
+        assert!(mul_num!(two!(), 0.25) == half!());
+        assert!(mul_num!(ten!(), -1) == neg_ten!());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        mul_num!(three!(), 1000_u64)
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 3000);
+}
+
+#[test]
+fn test_neg_literals() {
+    fn float_function<T: Float>() {
+        assert!(neg_one!() == -T::one());
+        assert!(neg_ten!() == -ten!());
+    }
+
+    fn int_function<T: PrimInt + num_traits::Signed>() {
+        assert!(neg_one!() == -T::one());
+        assert!(neg_five!() + five!() == zero!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    int_function::<i32>();
+    int_function::<i64>();
+}
+
+#[test]
+fn test_pow() {
+    fn float_function<T: Float>() {
+        assert!(pow10!(3) == thousand!());
+        assert!(pow10!(0) == one!());
+        assert!(pow10!(-2) == hundredth!());
+        assert!(pow!(2, 10) == T::from(1024).unwrap());
+        assert!(pow!(half!(), 2) == quarter!());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        pow10!(18)
+    }
+
+    // `10^320` overflows to `inf`, but `10^-320` is a subnormal `f64`.
+    fn subnormal_function<T: Float>() -> [T; 3] {
+        [pow10!(-320), pow10!(-323), pow10!(-324)]
+    }
+
+    float_function::<f64>();
+    assert!(subnormal_function::<f64>() == [1e-320, 1e-323, 0.0]);
+    assert!(subnormal_function::<f32>() == [0.0; 3]);
+
+    assert!(int_function::<i64>() == 1_000_000_000_000_000_000);
+    assert!(int_function::<u128>() == 1_000_000_000_000_000_000);
+}
+
+#[test]
+fn test_pow2() {
+    fn float_function<T: Float>() {
+        assert!(pow2!(10) == num!(1024));
+        assert!(pow2!(-1) == half!());
+        assert!(pow2!(0) == one!());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        assert!(pow2!(10) == num!(1024));
+        pow2!(31)
+    }
+
+    fn smallest_function<T: Float>() -> [T; 2] {
+        [pow2!(-1074), pow2!(-1075)]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+    assert!(smallest_function::<f64>() == [f64::from_bits(1), 0.0]);
+
+    assert!(int_function::<u32>() == 1 << 31);
+}
+
+#[test]
+fn test_num_panic_message() {
+    fn int_function<T: PrimInt>() -> T {
+        num!(300)
+    }
+
+    let error = std::panic::catch_unwind(int_function::<u8>).unwrap_err();
+    let message = error.downcast_ref::<String>().unwrap();
+    assert!(message.contains("300"));
+    assert!(message.contains("u8"));
+}
+
+#[test]
+fn test_num_or() {
+    fn int_function<T: PrimInt>(n: i32) -> T {
+        num_or!(n, T::max_value())
+    }
+
+    fn float_function<T: Float>() -> T {
+        num_or!(2.5, T::nan())
+    }
+
+    assert!(int_function::<u8>(300) == u8::MAX);
+    assert!(int_function::<u8>(42) == 42);
+    assert!(float_function::<f64>() == 2.5);
+}
+
+#[test]
+fn test_num_checked() {
+    fn int_function<T: PrimInt>(n: f64) -> Result<T, NumLazyError> {
+        num_checked!(n)
+    }
+
+    fn sum<T: PrimInt>(a: i32, b: i32) -> Result<T, NumLazyError> {
+        Ok(num_checked!(a)? + num_checked!(b)?)
+    }
+
+    assert!(int_function::<u8>(42.0) == Ok(42));
+    assert!(int_function::<u8>(300.0) == Err(NumLazyError::OutOfRange));
+    assert!(int_function::<u8>(-1.0) == Err(NumLazyError::OutOfRange));
+    assert!(int_function::<i32>(f64::NAN) == Err(NumLazyError::NotRepresentable));
+    assert!(sum::<u8>(200, 50) == Ok(250));
+    assert!(sum::<u8>(200, 256) == Err(NumLazyError::OutOfRange));
+    assert!(NumLazyError::OutOfRange.to_string() == "value is out of range of the target type");
+}
+
+#[test]
+fn test_saturating_num() {
+    fn int_function<T: PrimInt>() -> [T; 4] {
+        [
+            saturating_num!(300),
+            saturating_num!(-5),
+            saturating_num!(f64::NAN),
+            saturating_num!(42.9),
+        ]
+    }
+
+    fn float_function<T: Float>() -> [T; 2] {
+        [saturating_num!(1e300), saturating_num!(-2.5)]
+    }
+
+    assert!(int_function::<u8>() == [255, 0, 0, 42]);
+    assert!(int_function::<i8>() == [127, -5, 0, 42]);
+    assert!(int_function::<u32>() == [300, 0, 0, 42]);
+    assert!(float_function::<f32>() == [f32::INFINITY, -2.5]);
+}
+
+#[test]
+fn test_float_info() {
+    fn float_function<T: Float>() -> (u32, u32, u32, i32, i32) {
+        (
+            radix!(),
+            mantissa_digits!(),
+            digits!(),
+            min_exp!(),
+            max_exp!(),
+        )
+    }
+
+    assert!(float_function::<f32>() == (f32::RADIX, 24, f32::DIGITS, f32::MIN_EXP, f32::MAX_EXP));
+    assert!(float_function::<f64>() == (f64::RADIX, 53, f64::DIGITS, f64::MIN_EXP, f64::MAX_EXP));
+}
+
+#[test]
+fn test_clamp_num() {
+    fn float_function<T: Float>() {
+        assert!(clamp_num!(5, zero!(), two!()) == two!());
+        assert!(clamp_num!(-5, zero!(), two!()) == zero!());
+        assert!(clamp_num!(1.5, 0, 2) == T::from(1.5).unwrap());
+        assert!(clamp_num!(nan!(), zero!(), two!()).is_nan());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        clamp_num!(200, 0, 100)
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<u8>() == 100);
+}
+
+#[test]
+fn test_large_literals() {
+    fn float_function<T: Float>() {
+        assert!(ten!() * thousand!() == ten_thousand!());
+        assert!(ten!() * ten_thousand!() == hundred_thousand!());
+        assert!(thousand!() * million!() == billion!());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        assert!(hundred!() * thousand!() == hundred_thousand!());
+        billion!()
+    }
+
+    float_function::<f64>();
+    assert!(int_function::<i64>() == 1_000_000_000);
+}
+
+#[test]
+fn test_tiny() {
+    fn float_function<T: Float>() -> (T, T) {
+        assert!(tiny!() == min_positive!());
+        assert!(smallest_subnormal!() > zero!());
+        assert!(smallest_subnormal!() < tiny!());
+        assert!(smallest_subnormal!() * half!() == zero!());
+        (tiny!(), smallest_subnormal!())
+    }
+
+    assert!(float_function::<f64>() == (f64::MIN_POSITIVE, f64::from_bits(1)));
+    assert!(float_function::<f32>() == (f32::MIN_POSITIVE, f32::from_bits(1)));
+}
+
+#[test]
+fn test_percent() {
+    fn float_function<T: Float>() {
+        assert!(percent!(50) == half!());
+        assert!(percent!(100) == one!());
+        assert!(percent!(25.0) == quarter!());
+    }
+
+    fn int_function<T: PrimInt>() -> [T; 2] {
+        [percent!(50), percent!(250)]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == [0, 2]);
+}
+
+#[test]
+fn test_next_up_down() {
+    fn float_function<T: Float>() {
+        assert!(next_up!(one!()) - one!() == epsilon!());
+        assert!(one!() - next_down!(one!()) == epsilon!() * half!());
+        assert!(next_down!(next_up!(one!())) == one!());
+        assert!(next_up!(next_down!(neg_one!())) == neg_one!());
+        assert!(next_up!(zero!()) == smallest_subnormal!());
+        assert!(next_up!(neg_zero!()) == smallest_subnormal!());
+        assert!(next_down!(zero!()) == -smallest_subnormal!());
+        assert!(next_down!(tiny!()) < tiny!());
+        assert!(next_up!(next_down!(tiny!())) == tiny!());
+        assert!(next_up!(max_val!()) == inf!());
+        assert!(next_up!(inf!()) == inf!());
+        assert!(next_up!(neg_inf!()) == min_val!());
+        assert!(next_down!(inf!()) == max_val!());
+        assert!(next_down!(neg_inf!()) == neg_inf!());
+        assert!(next_up!(nan!()).is_nan());
+        assert!(next_down!(nan!()).is_nan());
+        let neg = next_up!(-smallest_subnormal!());
+        assert!(neg == zero!() && neg.is_sign_negative());
+    }
+
+    fn steps<T: Float>(x: T) -> (T, T) {
+        (next_up!(x), next_down!(x))
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    let values = [1.0, -1.0, 0.1, -3.5e300, 1e-310, -1e-310, f64::MIN_POSITIVE];
+    for x in values {
+        let (up, down) = steps(x);
+        assert!(up.to_bits() == x.next_up().to_bits());
+        assert!(down.to_bits() == x.next_down().to_bits());
+    }
+}
+
+#[test]
+fn test_fraction_literals() {
+    fn float_function<T: Float>() {
+        assert!(two_thirds!() == two!() * third!());
+        assert!(three_quarters!() == three!() * quarter!());
+        assert!(three_halves!() == three!() * half!());
+        assert!(five_sixths!() + num!(1.0 / 6.0) == one!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_num_type_name() {
+    fn type_name<T: Float>() -> &'static str {
+        num_type_name!()
+    }
+
+    fn int_type_name<T: PrimInt>() -> &'static str {
+        num_type_name!()
+    }
+
+    assert!(type_name::<f64>().contains("f64"));
+    assert!(type_name::<f32>().contains("f32"));
+    assert!(int_type_name::<u8>().contains("u8"));
+}
+
+#[test]
+fn test_num_bits() {
+    fn float_function<T: Float>() {
+        assert!(num_bits!(0x3FF0000000000000u64) == one!());
+        assert!(num_bits!(0x3F80_0000u32) == one!());
+        assert!(num_bits!(0xBFE0000000000000u64) == -half!());
+        assert!(num_bits!(0x7FF0000000000000u64) == inf!());
+    }
+
+    fn bits<T: Float>() -> (T, T) {
+        (num_bits!(0x3FB999999999999Au64), num_bits!(0x3DCC_CCCDu32))
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(bits::<f64>().0 == 0.1);
+    assert!(bits::<f32>().1 == 0.1);
+}
+
+#[test]
+fn test_float_predicates() {
+    fn float_function<T: Float>() {
+        assert!(is_inf!(inf!()));
+        assert!(is_inf!(neg_inf!()));
+        assert!(!is_inf!(max_val!()));
+        assert!(is_finite!(one!()));
+        assert!(!is_finite!(nan!()));
+        assert!(!is_finite!(neg_inf!()));
+        assert!(is_nan!(nan!()));
+        assert!(!is_nan!(inf!()));
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_min_max_val() {
+    fn float_function<T: Float>() -> (T, T) {
+        (min_val!(), max_val!())
+    }
+
+    fn int_function<T: PrimInt>() -> (T, T) {
+        (min_val!(), max_val!())
+    }
+
+    assert!(float_function::<f64>() == (f64::MIN, f64::MAX));
+    assert!(float_function::<f32>() == (f32::MIN, f32::MAX));
+    assert!(int_function::<u8>() == (0, u8::MAX));
+    assert!(int_function::<i64>() == (i64::MIN, i64::MAX));
+    assert!(int_function::<u128>() == (0, u128::MAX));
+}
+
+#[test]
+fn test_epsilon_tolerances() {
+    fn float_function<T: Float>() -> (T, T) {
+        assert!(half_epsilon!() * two!() == epsilon!());
+        assert!(one!() + half_epsilon!() == one!());
+        let sq = square!(sqrt_epsilon!());
+        assert!((sq - epsilon!()).abs() <= epsilon!() * epsilon!());
+        (half_epsilon!(), sqrt_epsilon!())
+    }
+
+    assert!(float_function::<f64>() == (1.1102230246251565e-16, 1.4901161193847656e-8));
+    assert!(float_function::<f32>() == (5.9604645e-8, 0.00034526698));
+}
+
+#[test]
+fn test_max_safe_int() {
+    fn float_function<T: Float>() -> T {
+        let n = max_safe_int!();
+        assert!(n - one!() != n);
+        assert!(n + one!() == n);
+        assert!(n == two!().powi(mantissa_digits!() as i32));
+        n
+    }
+
+    assert!(float_function::<f64>() == 9007199254740992.0);
+    assert!(float_function::<f32>() == 16777216.0);
+}
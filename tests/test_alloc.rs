@@ -0,0 +1,23 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#![cfg(feature = "alloc")]
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+
+#[test]
+fn test_num_vec() {
+    fn float_function<T: Float>() -> (Vec<T>, Vec<T>) {
+        (num_vec![1.0, 2, 3.5_f32, half!()], num_vec![])
+    }
+
+    let (values, empty) = float_function::<f64>();
+    assert!(values.len() == 4);
+    assert!(values == [1.0, 2.0, 3.5, 0.5]);
+    assert!(empty.is_empty());
+}
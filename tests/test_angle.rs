@@ -0,0 +1,83 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+mod common;
+
+use common::assert_close;
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+declare_nums! {@angle T}
+
+#[test]
+fn test_angle() {
+    fn float_function<T: Float>() {
+        assert_close(deg_180!(), pi!());
+        assert_close(deg_90!(), pi_2!());
+        assert_close(deg_270!(), three!() * pi_2!());
+        assert_close(deg_360!(), tau!());
+        assert_close(deg_to_rad!(num!(180)), pi!());
+        assert_close(rad_to_deg!(deg_45!()), num!(45));
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_deg() {
+    fn float_function<T: Float>() {
+        assert_close(deg!(180), pi!());
+        assert!(deg!(90) == pi_2!());
+        assert_close(deg!(30).sin(), half!());
+        assert_close(deg!(-45), -deg_45!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_wrap_angle() {
+    fn float_function<T: Float>() {
+        assert_close(wrap_angle!(tau!() + pi_2!()), pi_2!());
+        assert_close(wrap_angle!(-tau!() - pi_2!()), -pi_2!());
+        assert_close(wrap_angle!(num!(10) * tau!() + one!()), one!());
+        assert!(wrap_angle!(pi!()) == -pi!());
+        assert!(wrap_angle!(-pi!()) == -pi!());
+        assert!(wrap_angle!(zero!()) == zero!());
+        assert!(wrap_angle!(half!()) == half!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_deg_per_rad() {
+    fn float_function<T: Float>() {
+        assert_close(deg_per_rad!() * rad_per_deg!(), one!());
+        assert_close(rad_per_deg!() * num!(180), pi!());
+        assert!(deg_to_rad!(one!()) == rad_per_deg!());
+        assert!(rad_to_deg!(one!()) == deg_per_rad!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_to_degrees_radians() {
+    fn float_function<T: Float>() {
+        assert_close(to_degrees!(pi!()), num!(180));
+        assert_close(to_radians!(num!(180)), pi!());
+        assert_close(to_radians!(to_degrees!(deg_30!())), deg_30!());
+        assert!(to_degrees!(zero!()) == zero!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
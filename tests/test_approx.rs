@@ -0,0 +1,53 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+declare_nums! {@approx T}
+
+#[test]
+fn test_close_to() {
+    fn float_function<T: Float>() {
+        assert!(close_to!(third!() * three!(), one!()));
+        assert!(close_to!(tenth!() + tenth!() + tenth!(), num!(0.3)));
+        assert!(close_to!(million!() + epsilon!(), million!()));
+        assert!(!close_to!(one!(), one!() + tenth!()));
+        assert!(close_to!(one!(), one!() + tenth!(), tenth!()));
+        assert!(!close_to!(nan!(), nan!()));
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_is_negligible() {
+    fn float_function<T: Float>() {
+        assert!(is_negligible!(epsilon!() * half!()));
+        assert!(is_negligible!(neg_zero!()));
+        assert!(!is_negligible!(epsilon!()));
+        assert!(!is_negligible!(one!()));
+        assert!(!is_negligible!(nan!()));
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_nan_safe_eq() {
+    fn float_function<T: Float>() {
+        assert!(nan_safe_eq!(nan!(), nan!()));
+        assert!(nan_safe_eq!(one!(), one!()));
+        assert!(nan_safe_eq!(zero!(), neg_zero!()));
+        assert!(!nan_safe_eq!(nan!(), one!()));
+        assert!(!nan_safe_eq!(one!(), two!()));
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
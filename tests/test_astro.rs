@@ -0,0 +1,22 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@astro T}
+
+#[test]
+fn test_astro() {
+    fn float_function<T: Float>() -> T {
+        assert!(au!() == T::from(1.495978707e11).unwrap());
+        assert!(julian_year!() == T::from(365.25 * 86400.0).unwrap());
+        assert!(solar_mass!() > T::one());
+        standard_gravity!()
+    }
+
+    assert!(float_function::<f64>() == 9.80665);
+    assert!((float_function::<f32>() - 9.80665).abs() <= f32::EPSILON * 9.80665);
+}
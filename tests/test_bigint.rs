@@ -0,0 +1,92 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_bigint::{BigInt, BigUint};
+
+mod generic {
+    use num_lazy::declare_nums;
+    use num_traits::{FromPrimitive, Signed};
+
+    declare_nums! {@integer T}
+    declare_nums! {@fallible T}
+
+    pub fn values<T: FromPrimitive + Signed>() -> [T; 4] {
+        [
+            hundred!(),
+            million!(),
+            zero!() - ten!(),
+            try_num!(u64::MAX).unwrap() * two!(),
+        ]
+    }
+}
+
+mod concrete {
+    use num_bigint::BigUint;
+    use num_lazy::declare_nums;
+
+    declare_nums! {BigUint}
+
+    pub fn values() -> [BigUint; 4] {
+        [
+            hundred!(),
+            million!(),
+            half!(),
+            try_num!(-1).unwrap_or(thousand!()),
+        ]
+    }
+}
+
+mod sources {
+    use num_bigint::BigInt;
+    use num_lazy::{declare_nums, NumLazyError};
+    use num_traits::{Float, PrimInt};
+
+    declare_nums! {@fallible T}
+
+    // `BigInt` is not `Copy`, so the source must not be reused after it is converted.
+    pub fn float_checked<T: Float>(n: BigInt) -> Result<T, NumLazyError> {
+        num_checked!(n)
+    }
+
+    pub fn int_checked<T: PrimInt>(n: BigInt) -> Result<T, NumLazyError> {
+        num_checked!(n)
+    }
+
+    pub fn int_saturating<T: PrimInt>(n: BigInt) -> T {
+        saturating_num!(n)
+    }
+}
+
+#[test]
+fn test_bigint_source() {
+    assert!(sources::float_checked::<f64>(BigInt::from(-7)) == Ok(-7.0));
+    assert!(sources::int_checked::<u8>(BigInt::from(200)) == Ok(200));
+    assert!(
+        sources::int_checked::<u8>(BigInt::from(256)) == Err(num_lazy::NumLazyError::OutOfRange)
+    );
+    assert!(
+        sources::int_checked::<u8>(BigInt::from(-1)) == Err(num_lazy::NumLazyError::OutOfRange)
+    );
+    assert!(sources::int_saturating::<u8>(BigInt::from(300)) == 255);
+    assert!(sources::int_saturating::<i8>(BigInt::from(-300)) == -128);
+    assert!(sources::int_saturating::<u8>(BigInt::from(7)) == 7);
+}
+
+#[test]
+fn test_bigint() {
+    let big = |s: &str| s.parse::<BigInt>().unwrap();
+    assert!(
+        generic::values::<BigInt>()
+            == [
+                big("100"),
+                big("1000000"),
+                big("-10"),
+                big("36893488147419103230")
+            ]
+    );
+
+    let big = |s: &str| s.parse::<BigUint>().unwrap();
+    assert!(concrete::values() == [big("100"), big("1000000"), big("0"), big("1000")]);
+}
@@ -0,0 +1,25 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#![cfg(feature = "num-complex")]
+
+use num_complex::Complex;
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@complex T}
+
+#[test]
+fn test_complex() {
+    fn float_function<T: Float>() {
+        assert!(i!() * i!() == -complex_one!());
+        assert!(complex!(1, 2) == Complex::new(T::one(), T::from(2).unwrap()));
+        assert!(complex_zero!() + complex_one!() == complex!(1, 0));
+        assert!(complex!(0.5, -0.5) * complex!(2, 0) == complex_one!() - i!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
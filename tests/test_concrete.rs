@@ -0,0 +1,84 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+mod single {
+    use num_lazy::declare_nums;
+    use num_traits::Float;
+
+    declare_nums! {f32}
+
+    const TAU_F32: f32 = tau!();
+
+    pub fn check() {
+        assert!(zero!() == 0.0_f32);
+        assert!(num!(42.42) == 42.42_f32);
+        assert!(try_num!(2.5) == Some(2.5_f32));
+        const { assert!(pi!() == core::f32::consts::PI) };
+        assert!(half!() * tau!() == pi!());
+        assert!(epsilon!() == f32::EPSILON);
+        assert!(max_val!() == f32::MAX);
+        assert!(recip!(four!()) == quarter!());
+        const { assert!(TAU_F32 == core::f32::consts::TAU) };
+        const { assert!(num_bits!(0x3F80_0000u32) == 1.0) };
+        const { assert!(num_bits!(0x3FF0000000000000u64) == 1.0) };
+        assert!(num_bits!(0x3FB999999999999Au64) == 0.1);
+    }
+}
+
+mod unsigned {
+    use num_lazy::declare_nums;
+
+    declare_nums! {u8}
+
+    pub fn check() {
+        assert!(max_val!() == u8::MAX);
+        assert!(min_val!() == 0);
+    }
+}
+
+mod wide {
+    use num_lazy::declare_nums;
+
+    declare_nums! {u128}
+
+    // `u128` implements both `NumCast` and `FromPrimitive`, and only `NumCast` is exact here.
+    pub fn check() {
+        assert!(num!(u128::MAX) == u128::MAX);
+        assert!(try_num!(u128::MAX - 1) == Some(u128::MAX - 1));
+    }
+}
+
+declare_nums! {f64}
+
+const PI: f64 = pi!();
+const THIRD: f64 = third!();
+static MILLION: f64 = million!();
+
+#[test]
+fn test_concrete() {
+    assert!(one!() == 1.0_f64);
+    assert!(num!(42.42) == 42.42_f64);
+    assert!(frac!(1, 4) == quarter!());
+    assert!(nums![1, 2] == [1.0_f64, 2.0]);
+    const { assert!(pi!() == core::f64::consts::PI) };
+    const { assert!(e!() == core::f64::consts::E) };
+    assert!(inf!() == f64::INFINITY);
+    assert!(nan!().is_nan());
+    assert!(square!(three!()) == nine!());
+    const { assert!(PI == core::f64::consts::PI) };
+    const { assert!(THIRD == 1.0 / 3.0) };
+    assert!(MILLION == 1e6);
+    const { assert!(num_bits!(0x3FF0000000000000u64) == one!()) };
+    assert!(num_bits!(1u64).to_bits() == 1);
+    const { assert!(num_bits!(0x3F80_0000u32) == one!()) };
+    assert!(num_bits!(0x3DCC_CCCDu32) == 0.1_f32 as f64);
+
+    single::check();
+    unsigned::check();
+    wide::check();
+}
@@ -0,0 +1,159 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+mod common;
+
+use common::assert_close;
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+
+#[test]
+fn test_roots() {
+    fn float_function<T: Float>() {
+        assert_close(sqrt_3!(), three!().sqrt());
+        assert_close(sqrt_5!(), five!().sqrt());
+        assert_close(sqrt_pi!(), pi!().sqrt());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_analysis_constants() {
+    fn float_function<T: Float>() -> [T; 3] {
+        [euler_gamma!(), catalan!(), apery!()]
+    }
+
+    assert!(float_function::<f64>() == [0.5772156649015329, 0.915965594177219, 1.2020569031595942]);
+    assert!(float_function::<f32>() == [0.5772157, 0.9159656, 1.2020569]);
+}
+
+#[test]
+fn test_reciprocals() {
+    fn float_function<T: Float>() {
+        assert_close(frac_1_phi!(), phi!() - one!());
+        assert_close(frac_1_e!(), e!().recip());
+        assert_close(frac_1_tau!(), tau!().recip());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_f32_consts_bit_exact() {
+    fn float_function<T: Float>() -> [T; 18] {
+        [
+            pi!(),
+            pi_2!(),
+            pi_3!(),
+            pi_4!(),
+            pi_6!(),
+            frac_1_pi!(),
+            frac_2_pi!(),
+            frac_2_sqrt_pi!(),
+            tau!(),
+            e!(),
+            ln_2!(),
+            ln_10!(),
+            log2_10!(),
+            log2_e!(),
+            log10_2!(),
+            log10_e!(),
+            sqrt_2!(),
+            frac_1_sqrt_2!(),
+        ]
+    }
+
+    use core::f32::consts::*;
+    let expected = [
+        PI,
+        FRAC_PI_2,
+        FRAC_PI_3,
+        FRAC_PI_4,
+        FRAC_PI_6,
+        FRAC_1_PI,
+        FRAC_2_PI,
+        FRAC_2_SQRT_PI,
+        TAU,
+        E,
+        LN_2,
+        LN_10,
+        LOG2_10,
+        LOG2_E,
+        LOG10_2,
+        LOG10_E,
+        SQRT_2,
+        FRAC_1_SQRT_2,
+    ];
+    for (a, b) in float_function::<f32>().iter().zip(expected) {
+        assert!(a.to_bits() == b.to_bits());
+    }
+}
+
+#[test]
+fn test_log_constants() {
+    fn float_function<T: Float>() {
+        assert_close(ln_pi!(), pi!().ln());
+        assert_close(log10_pi!(), pi!().log10());
+        assert_close(ln_phi!(), phi!().ln());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_pi_multiples() {
+    fn float_function<T: Float>() {
+        assert!(pi_4!() == half!() * pi_2!());
+        assert_close(pi_6!(), half!() * pi_3!());
+        assert_close(three_pi_2!(), three!() * pi_2!());
+        assert!(four_pi!() == two!() * tau!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_gamma_constants() {
+    fn float_function<T: Float>() {
+        assert_close(sqrt_half_pi!(), pi_2!().sqrt());
+        assert!(gamma_half!() == sqrt_pi!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_squared_constants() {
+    fn float_function<T: Float>() {
+        assert_close(pi_squared!(), pi!() * pi!());
+        assert_close(e_squared!(), e!() * e!());
+        assert_close(tau_squared!(), tau!() * tau!());
+        assert!(tau_squared!() == four!() * pi_squared!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_metallic_means() {
+    fn float_function<T: Float>() {
+        assert_close(silver_ratio!(), one!() + sqrt_2!());
+        assert!(phi_conjugate!() == -frac_1_phi!());
+        assert_close(phi!() + phi_conjugate!(), one!());
+        assert_close(phi!() * phi_conjugate!(), neg_one!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
@@ -0,0 +1,57 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use rust_decimal::Decimal;
+
+mod generic {
+    use core::ops::{Add, Mul};
+    use num_lazy::declare_nums;
+    use num_traits::FromPrimitive;
+
+    declare_nums! {T}
+
+    pub fn values<T: FromPrimitive + Copy + Add<Output = T> + Mul<Output = T>>() -> [T; 5] {
+        [
+            half!(),
+            hundred!(),
+            tenth!() + tenth!(),
+            num!(42),
+            num!(2.5) * ten!(),
+        ]
+    }
+}
+
+mod concrete {
+    use num_lazy::declare_nums;
+    use rust_decimal::Decimal;
+
+    declare_nums! {Decimal}
+
+    pub fn values() -> [Decimal; 3] {
+        [thousandth!(), num!(i64::MAX), num_or!(f64::NAN, neg_one!())]
+    }
+}
+
+#[test]
+fn test_decimal() {
+    assert!(
+        generic::values::<Decimal>()
+            == [
+                Decimal::new(5, 1),
+                Decimal::new(100, 0),
+                Decimal::new(2, 1),
+                Decimal::new(42, 0),
+                Decimal::new(25, 0)
+            ]
+    );
+    assert!(
+        concrete::values()
+            == [
+                Decimal::new(1, 3),
+                Decimal::new(i64::MAX, 0),
+                Decimal::new(-1, 0)
+            ]
+    );
+}
@@ -0,0 +1,26 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@em T}
+declare_nums! {@physics T}
+
+#[test]
+fn test_em() {
+    fn float_function<T: Float>() -> [T; 3] {
+        // c² ε₀ μ₀ = 1 up to the rounding of the measured ε₀ and μ₀.
+        let one = speed_of_light!().powi(2) * epsilon_0!() * mu_0!();
+        assert!((one - T::one()).abs() <= T::from(1e-6).unwrap());
+        [epsilon_0!(), mu_0!(), impedance_free_space!()]
+    }
+
+    let expected = [8.8541878128e-12, 1.25663706212e-6, 376.730313668];
+    assert!(float_function::<f64>() == expected);
+    for (a, b) in float_function::<f32>().iter().zip(expected) {
+        assert!((*a as f64 - b).abs() <= f32::EPSILON as f64 * b);
+    }
+}
@@ -0,0 +1,51 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+mod excluded {
+    use num_lazy::declare_nums;
+    use num_traits::Float;
+
+    declare_nums! {T, except[half, third]}
+
+    // Would be ambiguous with a generated `half!` or `third!`.
+    macro_rules! half {
+        () => {
+            "half"
+        };
+    }
+
+    macro_rules! third {
+        () => {
+            "third"
+        };
+    }
+
+    pub fn check<T: Float>() {
+        assert!(quarter!() == T::from(0.25).unwrap());
+        assert!(num!(2) * quarter!() == T::from(0.5).unwrap());
+        assert!(pi!() == T::from(core::f64::consts::PI).unwrap());
+        assert!(half!() == "half");
+        assert!(third!() == "third");
+    }
+}
+
+mod empty {
+    use num_lazy::declare_nums;
+    use num_traits::Float;
+
+    declare_nums! {T, except[]}
+
+    pub fn half_pi<T: Float>() -> T {
+        half!() * pi!()
+    }
+}
+
+#[test]
+fn test_except() {
+    excluded::check::<f64>();
+    excluded::check::<f32>();
+
+    assert!(empty::half_pi::<f64>() == core::f64::consts::FRAC_PI_2);
+}
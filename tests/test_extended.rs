@@ -0,0 +1,20 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+declare_nums! {@extended T}
+
+#[test]
+fn test_extended() {
+    fn float_function<T: Float>() -> [T; 2] {
+        assert!(deg_180!() == pi!());
+        [sqrt_2pi!(), deg_90!()]
+    }
+
+    assert!(float_function::<f64>() == [2.5066282746310002, core::f64::consts::FRAC_PI_2]);
+}
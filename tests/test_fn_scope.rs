@@ -0,0 +1,30 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+fn circle_area<T: Float>(radius: T) -> T {
+    declare_nums! {T}
+    pi!() * square!(radius)
+}
+
+fn scaled<T: Float>(values: &[T]) -> T {
+    declare_nums! {@prefix s_ T}
+    values.iter().fold(s_zero!(), |acc, &x| acc + x * s_half!())
+}
+
+fn quarter_of<T: Float>(a: T) -> T {
+    declare_nums! {T, except[half]}
+    a * quarter!()
+}
+
+#[test]
+fn test_fn_scope() {
+    assert!(circle_area(1.0_f64) == core::f64::consts::PI);
+    assert!(circle_area(2.0_f32) == 4.0 * core::f32::consts::PI);
+    assert!(scaled(&[1.0_f64, 3.0]) == 2.0);
+    assert!(quarter_of(2.0_f64) == 0.5);
+}
@@ -0,0 +1,102 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#![cfg(feature = "half")]
+
+use half::{bf16, f16};
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+
+#[test]
+fn test_half() {
+    fn float_function<T: Float>() -> [T; 5] {
+        assert!(half!() + half!() == one!());
+        assert!(num!(2.5) == T::from(2.5).unwrap());
+        assert!(min_positive!() > zero!() && min_positive!() < epsilon!());
+        assert!(nan!().is_nan());
+        [pi!(), inf!(), epsilon!(), max_val!(), min_positive!()]
+    }
+
+    assert!(
+        float_function::<f16>()
+            == [
+                f16::PI,
+                f16::INFINITY,
+                f16::EPSILON,
+                f16::MAX,
+                f16::MIN_POSITIVE
+            ]
+    );
+    assert!(
+        float_function::<bf16>()
+            == [
+                bf16::PI,
+                bf16::INFINITY,
+                bf16::EPSILON,
+                bf16::MAX,
+                bf16::MIN_POSITIVE
+            ]
+    );
+}
+
+#[test]
+fn test_half_float_info() {
+    fn float_function<T: Float>() -> (u32, u32, u32, i32, i32) {
+        (
+            radix!(),
+            mantissa_digits!(),
+            digits!(),
+            min_exp!(),
+            max_exp!(),
+        )
+    }
+
+    assert!(
+        float_function::<f16>()
+            == (
+                f16::RADIX,
+                f16::MANTISSA_DIGITS,
+                f16::DIGITS,
+                f16::MIN_EXP,
+                f16::MAX_EXP
+            )
+    );
+    assert!(
+        float_function::<bf16>()
+            == (
+                bf16::RADIX,
+                bf16::MANTISSA_DIGITS,
+                bf16::DIGITS,
+                bf16::MIN_EXP,
+                bf16::MAX_EXP
+            )
+    );
+}
+
+#[test]
+fn test_half_next_up_down() {
+    fn steps<T: Float>(x: T) -> (T, T) {
+        assert!(next_up!(one!()) - one!() == epsilon!());
+        assert!(next_down!(next_up!(x)) == x);
+        assert!(next_up!(zero!()) == smallest_subnormal!());
+        (next_up!(x), next_down!(x))
+    }
+
+    // Adjacent positive values differ by one in their bit patterns.
+    for bits in [0x3C00, 0x3C01, 0x4000, 0x0400, 0x0001, 0x0200, 0x7BFF] {
+        let x = f16::from_bits(bits);
+        let down = if bits == 0x0001 { 0 } else { bits - 1 };
+        assert!(steps(x) == (f16::from_bits(bits + 1), f16::from_bits(down)));
+        assert!(steps(-x) == (-f16::from_bits(down), -f16::from_bits(bits + 1)));
+    }
+    for bits in [0x3F80, 0x3F81, 0x4000, 0x0080, 0x0001, 0x0040, 0x7F7F] {
+        let x = bf16::from_bits(bits);
+        let down = if bits == 0x0001 { 0 } else { bits - 1 };
+        assert!(steps(x) == (bf16::from_bits(bits + 1), bf16::from_bits(down)));
+        assert!(steps(-x) == (-bf16::from_bits(down), -bf16::from_bits(bits + 1)));
+    }
+}
@@ -0,0 +1,10 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#[test]
+fn test_hygiene() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/ui/*.rs");
+}
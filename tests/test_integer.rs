@@ -0,0 +1,25 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::PrimInt;
+
+declare_nums! {@integer T}
+
+#[test]
+fn test_integer() {
+    fn int_function<T: PrimInt>() {
+        assert!(zero!() == T::zero());
+        assert!(one!() == T::one());
+        assert!(five!() + five!() == ten!());
+        assert!(ten!() * ten!() == hundred!());
+        assert!(thousand!() * thousand!() == million!());
+        assert!(million!() == T::from(1_000_000).unwrap());
+    }
+
+    int_function::<i32>();
+    int_function::<u64>();
+    int_function::<i128>();
+}
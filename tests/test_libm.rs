@@ -0,0 +1,37 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Ensure the float helpers work in `no_std` through `libm`, without importing `Float`.
+
+#![no_std]
+#![cfg(feature = "libm")]
+
+use num_lazy::declare_nums;
+
+declare_nums! {f64}
+declare_nums! {@angle f64}
+declare_nums! {@approx f64}
+
+#[test]
+fn test_libm_angle() {
+    assert!(wrap_angle!(pi!()) == -pi!());
+    assert!(wrap_angle!(-pi!()) == -pi!());
+    assert!(close_to!(
+        wrap_angle!(3.0 * pi!() + 0.5),
+        0.5 - pi!(),
+        1e-12
+    ));
+    assert!(close_to!(wrap_angle!(-tau!() - 1.0), -1.0, 1e-12));
+}
+
+#[test]
+fn test_libm_ops() {
+    assert!(abs_diff!(1.0, 3.5) == 2.5);
+    assert!(abs_diff!(-2.0, 1.0) == 3.0);
+    assert!(copysign_one!(-0.0) == -1.0);
+    assert!(is_negligible!(1e-17));
+    assert!(!is_negligible!(1e-3));
+    assert!(fma!(2.0, 3.0, 1.0) == 7.0);
+}
@@ -0,0 +1,57 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#![cfg(feature = "log")]
+
+use std::sync::Mutex;
+
+use log::{Level, LevelFilter, Log, Metadata, Record};
+use num_bigint::BigInt;
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+
+struct TestLogger(Mutex<Vec<(Level, String)>>);
+
+impl Log for TestLogger {
+    fn enabled(&self, _: &Metadata) -> bool {
+        true
+    }
+
+    fn log(&self, record: &Record) {
+        let entry = (record.level(), record.args().to_string());
+        self.0.lock().unwrap().push(entry);
+    }
+
+    fn flush(&self) {}
+}
+
+static LOGGER: TestLogger = TestLogger(Mutex::new(Vec::new()));
+
+#[test]
+fn test_debug_num() {
+    fn float_function<T: Float + core::fmt::Debug>() -> (T, T) {
+        (debug_num!(0.1), num!(0.1))
+    }
+
+    // `BigInt` is not `Copy`, so the source must be formatted before it is converted.
+    fn big_function<T: Float + core::fmt::Debug>(n: BigInt) -> T {
+        debug_num!(n)
+    }
+
+    log::set_logger(&LOGGER).unwrap();
+    log::set_max_level(LevelFilter::Trace);
+
+    let (logged, plain) = float_function::<f32>();
+    assert!(logged == plain);
+    assert!(big_function::<f64>(BigInt::from(-3)) == -3.0);
+
+    let records = LOGGER.0.lock().unwrap();
+    assert!(records.len() == 2);
+    assert!(records[0].0 == Level::Trace);
+    assert!(records[0].1 == "debug_num!(0.1): 0.1 into `f32` = 0.1");
+    assert!(records[1].1 == "debug_num!(n): -3 into `f64` = -3.0");
+}
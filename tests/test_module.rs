@@ -0,0 +1,31 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@module nums T}
+
+mod child {
+    use num_traits::Float;
+
+    pub fn circumference<T: Float>(radius: T) -> T {
+        crate::nums::two!() * crate::nums::pi!() * radius
+    }
+}
+
+#[test]
+fn test_module() {
+    fn float_function<T: Float>() {
+        assert!(nums::pi!() == T::from(core::f64::consts::PI).unwrap());
+        assert!(nums::num!(42.42) == T::from(42.42).unwrap());
+        assert!(nums::half!() * nums::tau!() == nums::pi!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(child::circumference(1.0_f64) == core::f64::consts::TAU);
+}
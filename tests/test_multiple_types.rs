@@ -0,0 +1,24 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T, U}
+
+#[test]
+fn test_multiple_types() {
+    fn float_function<T: Float, U: Float>() {
+        assert!(pi_t!() == T::from(core::f64::consts::PI).unwrap());
+        assert!(pi_u!() == U::from(core::f64::consts::PI).unwrap());
+        assert!(half_t!() + half_t!() == one_t!());
+        assert!(num_u!(42.42) == U::from(42.42).unwrap());
+        assert!(try_num_t!(1.5) == T::from(1.5));
+        assert!(epsilon_u!() == U::epsilon());
+    }
+
+    float_function::<f32, f64>();
+    float_function::<f64, f32>();
+}
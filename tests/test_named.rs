@@ -0,0 +1,34 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@named T, gravity = 9.81, c = 2.998e8,}
+
+mod concrete {
+    use num_lazy::declare_nums;
+
+    declare_nums! {@named f64, answer = 42, ratio = 1.0 / 8.0}
+
+    pub const ANSWER: f64 = answer!();
+
+    pub fn check() {
+        const { assert!(ANSWER == 42.0) };
+        const { assert!(ratio!() == 0.125) };
+    }
+}
+
+#[test]
+fn test_named() {
+    fn float_function<T: Float>() -> [T; 2] {
+        [gravity!(), c!()]
+    }
+
+    assert!(float_function::<f64>() == [9.81, 2.998e8]);
+    assert!(float_function::<f32>() == [9.81, 2.998e8]);
+
+    concrete::check();
+}
@@ -0,0 +1,93 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+
+declare_nums! {@names T}
+
+#[test]
+fn test_literal_names() {
+    assert!(NUM_LAZY_LITERALS.contains(&"half"));
+    assert!(
+        NUM_LAZY_LITERALS
+            == [
+                "zero",
+                "one",
+                "two",
+                "three",
+                "four",
+                "five",
+                "six",
+                "seven",
+                "eight",
+                "nine",
+                "ten",
+                "neg_one",
+                "neg_two",
+                "neg_three",
+                "neg_four",
+                "neg_five",
+                "neg_six",
+                "neg_seven",
+                "neg_eight",
+                "neg_nine",
+                "neg_ten",
+                "hundred",
+                "thousand",
+                "ten_thousand",
+                "hundred_thousand",
+                "million",
+                "billion",
+                "half",
+                "third",
+                "quarter",
+                "two_thirds",
+                "three_quarters",
+                "five_sixths",
+                "three_halves",
+                "tenth",
+                "hundredth",
+                "thousandth",
+                "millionth",
+                "percent",
+            ]
+    );
+    assert!(NUM_LAZY_INTEGERS.contains(&"million"));
+    assert!(!NUM_LAZY_INTEGERS.contains(&"half"));
+}
+
+#[test]
+fn test_names_unique() {
+    // `@integer` re-exports a subset of `@literal`, so it is checked against it instead.
+    let groups = [
+        NUM_LAZY_NUMS,
+        NUM_LAZY_FALLIBLE,
+        NUM_LAZY_LITERALS,
+        NUM_LAZY_CONSTANTS,
+        NUM_LAZY_SPECIALS,
+        NUM_LAZY_OPS,
+        NUM_LAZY_ANGLES,
+        NUM_LAZY_TRIG,
+        NUM_LAZY_STATS,
+        NUM_LAZY_PHYSICS,
+        NUM_LAZY_ASTRO,
+        NUM_LAZY_THERMO,
+        NUM_LAZY_EM,
+        NUM_LAZY_APPROX,
+        NUM_LAZY_PARSE,
+        NUM_LAZY_COMPLEX,
+    ];
+    let names: Vec<&str> = groups.iter().flat_map(|g| g.iter().copied()).collect();
+    for (i, name) in names.iter().enumerate() {
+        assert!(!names[i + 1..].contains(name), "{name} is declared twice");
+    }
+    for (i, name) in NUM_LAZY_INTEGERS.iter().enumerate() {
+        assert!(
+            !NUM_LAZY_INTEGERS[i + 1..].contains(name),
+            "{name} is declared twice"
+        );
+        assert!(NUM_LAZY_LITERALS.contains(name), "{name} is not a literal");
+    }
+}
@@ -0,0 +1,33 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+//! Ensure the generated macros only rely on `core` and `num_traits` without `std`.
+
+#![no_std]
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+
+fn circumference<T: Float>(radius: T) -> T {
+    two!() * pi!() * radius
+}
+
+fn specials<T: Float>() -> [T; 4] {
+    [inf!(), epsilon!(), neg_zero!(), num!(0.5)]
+}
+
+#[test]
+fn test_no_std() {
+    assert!(circumference(1.0_f64) == core::f64::consts::TAU);
+    assert!(circumference(1.0_f32) == core::f32::consts::TAU);
+
+    let [inf, eps, neg_zero, half] = specials::<f64>();
+    assert!(inf == f64::INFINITY);
+    assert!(eps == f64::EPSILON);
+    assert!(neg_zero == 0.0 && neg_zero.is_sign_negative());
+    assert!(half == 0.5);
+}
@@ -0,0 +1,56 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+mod allowed {
+    use num_lazy::declare_nums;
+    use num_traits::Float;
+
+    declare_nums! {T, only[pi, tau, half, lerp]}
+
+    // Would be ambiguous with a generated `e!` or `two!`.
+    macro_rules! e {
+        () => {
+            "e"
+        };
+    }
+
+    macro_rules! two {
+        () => {
+            "two"
+        };
+    }
+
+    pub fn check<T: Float>() {
+        assert!(pi!() == T::from(core::f64::consts::PI).unwrap());
+        assert!(half!() * tau!() == pi!());
+        assert!(lerp!(pi!(), tau!(), half!()) == T::from(1.5 * core::f64::consts::PI).unwrap());
+        assert!(e!() == "e");
+        assert!(two!() == "two");
+    }
+}
+
+mod single {
+    use num_lazy::declare_nums;
+
+    declare_nums! {f64, only[pi]}
+
+    macro_rules! e {
+        () => {
+            2.0
+        };
+    }
+
+    pub fn check() {
+        const { assert!(pi!() == core::f64::consts::PI) };
+        const { assert!(e!() == 2.0) };
+    }
+}
+
+#[test]
+fn test_only() {
+    allowed::check::<f64>();
+    allowed::check::<f32>();
+    single::check();
+}
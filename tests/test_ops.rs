@@ -0,0 +1,414 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::{Euclid, Float, PrimInt, Signed};
+
+declare_nums! {T}
+
+#[test]
+fn test_recip_square() {
+    fn float_function<T: Float>() {
+        assert!(recip!(two!()) == half!());
+        assert!(recip!(four!()) == quarter!());
+        assert!(square!(three!()) == nine!());
+        assert!(square!(half!()) == quarter!());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        square!(three!() + one!())
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 16);
+}
+
+#[test]
+fn test_cube_pow_n() {
+    fn float_function<T: Float>() {
+        assert!(cube!(two!()) == num!(8));
+        assert!(cube!(neg_one!()) == neg_one!());
+        assert!(pow_n!(two!(), 10) == num!(1024));
+        assert!(pow_n!(half!(), 2) == quarter!());
+        assert!(pow_n!(pi!(), 0) == one!());
+    }
+
+    fn int_function<T: PrimInt>() -> [T; 2] {
+        [cube!(three!()), pow_n!(ten!(), 3)]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i64>() == [27, 1000]);
+}
+
+#[test]
+fn test_fma() {
+    fn float_function<T: Float>() {
+        assert!(fma!(two!(), three!(), one!()) == num!(7));
+        assert!(fma!(half!(), four!(), neg_two!()) == zero!());
+        // (1 + ε)(1 - ε) - 1 = -ε², which rounds to 0 unless the product is not rounded first.
+        let a = one!() + epsilon!();
+        let b = one!() - epsilon!();
+        assert!(a * b - one!() == zero!());
+        assert!(fma!(a, b, neg_one!()) == -epsilon!() * epsilon!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+// With `libm`, `fma!` calls `Float::mul_add`, so only floats are accepted.
+#[cfg(not(feature = "libm"))]
+#[test]
+fn test_fma_int() {
+    fn int_function<T: PrimInt + num_traits::MulAdd<Output = T>>() -> T {
+        fma!(two!(), three!(), one!())
+    }
+
+    assert!(int_function::<i32>() == 7);
+    assert!(int_function::<u8>() == 7);
+}
+
+#[test]
+fn test_powf() {
+    fn float_function<T: Float>() {
+        assert!((powf!(four!(), half!()) - two!()).abs() <= epsilon!());
+        assert!((powf!(2, 0.5) - sqrt_2!()).abs() <= epsilon!());
+        assert!(powf!(pi!(), 0) == one!());
+        assert!((powf!(8, third!()) - two!()).abs() <= epsilon!() * two!());
+        assert!(powf!(-1, half!()).is_nan());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_lerp_midpoint() {
+    fn float_function<T: Float>() {
+        assert!(lerp!(zero!(), ten!(), half!()) == five!());
+        assert!(lerp!(two!(), four!(), zero!()) == two!());
+        assert!(lerp!(two!(), four!(), one!()) == four!());
+        assert!(midpoint!(two!(), four!()) == three!());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        midpoint!(two!(), five!())
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 3);
+}
+
+#[test]
+fn test_sign() {
+    fn float_function<T: Float>() {
+        assert!(copysign_one!(neg_two!()) == neg_one!());
+        assert!(copysign_one!(ten!()) == one!());
+        assert!(copysign_one!(neg_zero!()) == neg_one!());
+        assert!(sign_of!(neg_two!()) == neg_one!());
+        assert!(sign_of!(half!()) == one!());
+        assert!(sign_of!(zero!()) == zero!());
+        assert!(sign_of!(neg_zero!()).is_sign_negative());
+        assert!(sign_of!(nan!()).is_nan());
+    }
+
+    fn int_function<T: PrimInt>() -> [T; 3] {
+        [sign_of!(num!(-5)), sign_of!(zero!()), sign_of!(num!(7))]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == [-1, 0, 1]);
+    assert!(int_function::<i64>() == [-1, 0, 1]);
+}
+
+#[test]
+fn test_signum() {
+    fn float_function<T: Float>() {
+        assert!(signum!(neg_two!()) == neg_one!());
+        assert!(signum!(half!()) == one!());
+        assert!(signum!(zero!()) == one!());
+        assert!(signum!(neg_zero!()) == neg_one!());
+        assert!(signum!(nan!()).is_nan());
+    }
+
+    fn int_function<T: PrimInt + Signed>() -> [T; 3] {
+        [signum!(num!(-5)), signum!(zero!()), signum!(num!(7))]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == [-1, 0, 1]);
+    assert!(int_function::<i8>() == [-1, 0, 1]);
+}
+
+#[test]
+fn test_sign_predicates() {
+    fn float_function<T: Float>() {
+        assert!(is_negative!(neg_one!()));
+        assert!(!is_negative!(neg_zero!()));
+        assert!(is_positive!(tiny!()));
+        assert!(!is_positive!(zero!()));
+        assert!(is_zero!(zero!()));
+        assert!(is_zero!(neg_zero!()));
+        assert!(!is_zero!(nan!()) && !is_positive!(nan!()) && !is_negative!(nan!()));
+    }
+
+    fn int_function<T: PrimInt>() -> [bool; 3] {
+        [
+            is_positive!(one!()),
+            is_negative!(one!()),
+            is_zero!(zero!()),
+        ]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<u8>() == [true, false, true]);
+}
+
+#[test]
+fn test_max_min_of() {
+    fn float_function<T: Float>() {
+        assert!(max_of!(one!(), five!(), three!()) == five!());
+        assert!(min_of!(one!(), five!(), three!()) == one!());
+        assert!(max_of!(two!()) == two!());
+        assert!(max_of!(nan!(), two!(), one!()) == two!());
+        assert!(min_of!(two!(), nan!(), one!()) == one!());
+        assert!(max_of!(nan!(), nan!()).is_nan());
+    }
+
+    fn int_function<T: PrimInt>() -> (T, T) {
+        (
+            max_of!(num!(-3), num!(4), num!(2),),
+            min_of!(num!(-3), num!(4)),
+        )
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == (4, -3));
+}
+
+#[test]
+fn test_horner() {
+    fn float_function<T: Float>() {
+        assert!(horner!(two!(); 1, 0, 1) == five!());
+        assert!(horner!(half!(); 3) == three!());
+        assert!(horner!(neg_one!(); 1, 2, 3, 4,) == neg_two!());
+        assert!(horner!(ten!(); 0.5, 0.25) == num!(3));
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        horner!(ten!(); 3, 2, 1)
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 123);
+}
+
+#[test]
+fn test_euclid() {
+    fn float_function<T: Float + Euclid>() {
+        assert!(rem_euclid!(neg_one!(), three!()) == two!());
+        assert!(div_euclid!(neg_one!(), three!()) == neg_one!());
+        assert!(rem_euclid!(seven!(), three!()) == one!());
+        assert!(rem_euclid!(num!(-7.5), two!()) == half!());
+    }
+
+    fn int_function<T: PrimInt + Euclid>() -> [T; 2] {
+        [
+            rem_euclid!(num!(-7), four!()),
+            div_euclid!(num!(-7), four!()),
+        ]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == [1, -2]);
+}
+
+#[test]
+fn test_abs_diff() {
+    fn float_function<T: Float>() {
+        assert!(abs_diff!(two!(), five!()) == three!());
+        assert!(abs_diff!(five!(), two!()) == three!());
+        assert!(abs_diff!(neg_one!(), one!()) == two!());
+        assert!(abs_diff!(nan!(), one!()).is_nan());
+    }
+
+    fn int_function<T: PrimInt>() -> [T; 2] {
+        [abs_diff!(two!(), five!()), abs_diff!(five!(), two!())]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == [3, 3]);
+    assert!(int_function::<u8>() == [3, 3]);
+}
+
+#[test]
+fn test_clamp_to_unit() {
+    fn float_function<T: Float>() {
+        assert!(clamp_to_unit!(two!()) == one!());
+        assert!(clamp_to_unit!(neg_one!()) == zero!());
+        assert!(clamp_to_unit!(quarter!()) == quarter!());
+        assert!(clamp_to_unit!(nan!()).is_nan());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_clamp_nonneg_nonpos() {
+    fn float_function<T: Float>() {
+        assert!(clamp_nonneg!(neg_two!()) == zero!());
+        assert!(clamp_nonneg!(three!()) == three!());
+        assert!(clamp_nonpos!(three!()) == zero!());
+        assert!(clamp_nonpos!(neg_two!()) == neg_two!());
+        assert!(clamp_nonneg!(nan!()).is_nan());
+        assert!(clamp_nonpos!(nan!()).is_nan());
+    }
+
+    fn int_function<T: PrimInt>() -> [T; 2] {
+        [clamp_nonneg!(zero!() - five!()), clamp_nonpos!(five!())]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == [0, 0]);
+}
+
+#[test]
+fn test_wrap_unit() {
+    fn float_function<T: Float>() {
+        assert!((wrap_unit!(num!(1.25)) - quarter!()).abs() <= epsilon!());
+        assert!((wrap_unit!(neg_one!() * quarter!()) - num!(0.75)).abs() <= epsilon!());
+        assert!(wrap_unit!(neg_two!()) == zero!());
+        assert!(wrap_unit!(three!()) == zero!());
+        assert!(wrap_unit!(half!()) == half!());
+        assert!(wrap_unit!(-T::min_positive_value()) == zero!());
+        assert!(wrap_unit!(inf!()).is_nan());
+        assert!(wrap_unit!(nan!()).is_nan());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_round_to() {
+    fn float_function<T: Float>() {
+        assert!((round_to!(third!(), 2) - num!(0.33)).abs() <= epsilon!());
+        assert!(round_to!(num!(2.5), 0) == three!());
+        assert!(round_to!(num!(-2.5), 0) == -three!());
+        assert!(round_to!(num!(1234.5), -2) == num!(1200));
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_linspace_step() {
+    fn float_function<T: Float>() {
+        assert!(linspace_step!(zero!(), one!(), 5) == quarter!());
+        assert!(linspace_step!(one!(), neg_one!(), 3) == neg_one!());
+        assert!(linspace_step!(zero!(), one!(), 1).is_infinite());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        linspace_step!(zero!(), hundred!(), 11)
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 10);
+}
+
+#[test]
+fn test_checked_div() {
+    fn float_function<T: Float>() {
+        assert!(checked_div!(one!(), zero!()).is_none());
+        assert!(checked_div!(one!(), -zero!()).is_none());
+        assert!(checked_div!(four!(), two!()) == Some(two!()));
+        assert!(checked_div!(T::nan(), two!()).unwrap().is_nan());
+        assert!(checked_div!(one!(), T::nan()).unwrap().is_nan());
+        assert!(checked_div!(min_val!(), -one!()) == Some(max_val!()));
+        assert!(checked_div!(num!(-3), num!(-1.5)) == Some(two!()));
+    }
+
+    fn int_function<T: PrimInt>() -> [Option<T>; 2] {
+        [
+            checked_div!(seven!(), two!()),
+            checked_div!(one!(), zero!()),
+        ]
+    }
+
+    fn signed_function<T: PrimInt + Signed>() -> [Option<T>; 3] {
+        [
+            checked_div!(min_val!(), num!(-1)),
+            checked_div!(min_val!(), one!()),
+            checked_div!(num!(-6), num!(-1)),
+        ]
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == [Some(3), None]);
+    assert!(int_function::<u8>() == [Some(3), None]);
+    assert!(signed_function::<i32>() == [None, Some(i32::MIN), Some(6)]);
+    assert!(signed_function::<i8>() == [None, Some(i8::MIN), Some(6)]);
+    assert!(signed_function::<i128>() == [None, Some(i128::MIN), Some(6)]);
+}
+
+#[test]
+fn test_sat_add_mul() {
+    fn int_function<T: PrimInt>() {
+        assert!(sat_add!(max_val!(), one!()) == max_val!());
+        assert!(sat_add!(two!(), three!()) == five!());
+        assert!(sat_mul!(max_val!(), two!()) == max_val!());
+        assert!(sat_mul!(three!(), four!()) == num!(12));
+    }
+
+    fn signed_function<T: PrimInt + Signed>() {
+        assert!(sat_add!(min_val!(), neg_one!()) == min_val!());
+        assert!(sat_add!(max_val!(), neg_one!()) < max_val!());
+        assert!(sat_mul!(max_val!(), neg_two!()) == min_val!());
+        assert!(sat_mul!(min_val!(), neg_one!()) == max_val!());
+        assert!(sat_mul!(min_val!(), two!()) == min_val!());
+    }
+
+    int_function::<u8>();
+    int_function::<i32>();
+    int_function::<u64>();
+    signed_function::<i8>();
+    signed_function::<i64>();
+
+    fn u8_function<T: PrimInt>() -> [T; 2] {
+        [sat_add!(num!(200), num!(100)), sat_mul!(num!(16), num!(16))]
+    }
+
+    assert!(u8_function::<u8>() == [255, 255]);
+}
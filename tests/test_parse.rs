@@ -0,0 +1,22 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Num;
+
+declare_nums! {@parse T}
+
+#[test]
+fn test_from_str_num() {
+    fn parse<T: Num>(s: &str) -> Result<T, T::FromStrRadixErr> {
+        from_str_num!(s)
+    }
+
+    assert!(parse::<i32>("42") == Ok(42));
+    assert!(parse::<i32>("-7") == Ok(-7));
+    assert!(parse::<f64>("2.5").ok() == Some(2.5));
+    assert!(parse::<i32>("2.5").is_err());
+    assert!(parse::<f64>("pi").is_err());
+}
@@ -0,0 +1,51 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+mod types {
+    pub type Scalar = f32;
+
+    pub mod wide {
+        pub type Scalar = f64;
+    }
+}
+
+mod single {
+    use num_lazy::declare_nums;
+    use num_traits::Float;
+
+    declare_nums! {crate::types::Scalar}
+
+    pub fn check() {
+        assert!(pi!() == core::f32::consts::PI);
+        assert!(num!(2.5) == 2.5_f32);
+        assert!(epsilon!() == f32::EPSILON);
+        assert!(square!(three!()) == nine!());
+        assert!(close_to_one(next_down!(one!())));
+    }
+
+    fn close_to_one(x: crate::types::Scalar) -> bool {
+        (x - one!()).abs() <= epsilon!()
+    }
+}
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {types::wide::Scalar}
+
+#[test]
+fn test_path() {
+    assert!(tau!() == core::f64::consts::TAU);
+    assert!(frac!(1, 4) == quarter!());
+    assert!(max_val!() == f64::MAX);
+    assert!(clamp_to_four(pi!()) == pi!());
+    assert!(clamp_to_four(ten!()) == four!());
+
+    single::check();
+}
+
+fn clamp_to_four(x: types::wide::Scalar) -> types::wide::Scalar {
+    clamp_num!(x, -4, 4)
+}
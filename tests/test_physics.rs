@@ -0,0 +1,21 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@physics T}
+
+#[test]
+fn test_physics() {
+    fn float_function<T: Float>() -> T {
+        assert!(planck!() == T::from(6.62607015e-34).unwrap());
+        assert!(elementary_charge!() * avogadro!() > T::zero());
+        speed_of_light!()
+    }
+
+    assert!(float_function::<f64>() == 299792458.0_f64);
+    assert!(float_function::<f32>() == 299792458.0_f32);
+}
@@ -0,0 +1,25 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@prefix a_ T}
+declare_nums! {@prefix b_ U}
+
+#[test]
+fn test_prefix() {
+    fn float_function<T: Float, U: Float>() {
+        assert!(a_zero!() == T::zero());
+        assert!(a_half!() * a_pi!() == a_pi_2!());
+        assert!(a_num!(42.42) == T::from(42.42).unwrap());
+        assert!(b_one!() == U::one());
+        assert!(b_inf!() == U::infinity());
+        assert!(b_try_num!(2.5) == U::from(2.5));
+    }
+
+    float_function::<f64, f32>();
+    float_function::<f32, f64>();
+}
@@ -0,0 +1,27 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+
+declare_nums! {@pub T}
+
+mod consumer {
+    use num_traits::Float;
+
+    pub fn circle_area<T: Float>(radius: T) -> T {
+        pi!() * square!(radius)
+    }
+
+    pub fn halves<T: Float>() -> [T; 3] {
+        nums![half!(), one!(), one!() + half!()]
+    }
+}
+
+#[test]
+fn test_pub() {
+    assert!(consumer::circle_area(1.0_f64) == core::f64::consts::PI);
+    assert!(consumer::circle_area(2.0_f32) == 4.0 * core::f32::consts::PI);
+    assert!(consumer::halves::<f64>() == [0.5, 1.0, 1.5]);
+}
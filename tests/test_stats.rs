@@ -0,0 +1,46 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+mod common;
+
+use common::assert_close;
+use num_lazy::declare_nums;
+use num_traits::{Float, PrimInt};
+
+declare_nums! {T}
+declare_nums! {@stats T}
+
+#[test]
+fn test_stats() {
+    fn float_function<T: Float>() {
+        assert_close(sqrt_2pi!(), tau!().sqrt());
+        assert_close(frac_1_sqrt_2pi!(), one!() / tau!().sqrt());
+        assert_close(ln_2pi!(), tau!().ln());
+        assert_close(half_ln_2pi!(), half!() * tau!().ln());
+        assert_close(half_ln_2pi!(), sqrt_2pi!().ln());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+}
+
+#[test]
+fn test_mean() {
+    fn float_function<T: Float>() {
+        assert!(mean!(two!(), four!(), six!()) == four!());
+        assert!(mean!(three!()) == three!());
+        assert!(mean!(one!(), two!(),) == num!(1.5));
+        assert!(mean!(max_val!(), max_val!()).is_infinite());
+    }
+
+    fn int_function<T: PrimInt>() -> T {
+        mean!(num!(1), num!(2))
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+
+    assert!(int_function::<i32>() == 1);
+}
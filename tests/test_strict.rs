@@ -0,0 +1,36 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#![cfg(feature = "macros")]
+// `@strict` reports every generated macro that is not called, which is most of them here.
+#![allow(unused_macros)]
+
+mod declared {
+    use num_lazy::declare_nums;
+    use num_traits::Float;
+
+    declare_nums! {@strict T}
+
+    pub fn float_function<T: Float>() -> (T, T) {
+        (pi!(), frac!(1, 4))
+    }
+}
+
+mod attribute {
+    use num_lazy::with_nums;
+    use num_traits::Float;
+
+    #[with_nums(@strict T)]
+    pub fn tau_of<T: Float>() -> T {
+        tau!()
+    }
+}
+
+#[test]
+fn test_strict() {
+    assert!(declared::float_function::<f64>() == (core::f64::consts::PI, 0.25));
+    assert!(declared::float_function::<f32>() == (core::f32::consts::PI, 0.25));
+    assert!(attribute::tau_of::<f64>() == core::f64::consts::TAU);
+}
@@ -0,0 +1,22 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {@thermo T}
+
+#[test]
+fn test_thermo() {
+    fn float_function<T: Float>() -> T {
+        assert!(stefan_boltzmann!() == T::from(5.670374419e-8).unwrap());
+        assert!(standard_atm!() == T::from(101325).unwrap());
+        assert!(absolute_zero_c!() < T::zero());
+        gas_constant!()
+    }
+
+    assert!(float_function::<f64>() == 8.314462618);
+    assert!(float_function::<f32>() == 8.314463_f32);
+}
@@ -0,0 +1,63 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#![cfg(feature = "traits")]
+
+use num_lazy::traits::NumLazy;
+
+#[test]
+fn test_traits() {
+    fn float_function<T: NumLazy>() -> [T; 6] {
+        assert!(T::half() + T::half() == T::one());
+        assert!(T::neg_ten() == -T::ten());
+        assert!(T::third() * T::three() == T::one());
+        [
+            T::pi(),
+            T::e(),
+            T::sqrt_2(),
+            T::tau(),
+            T::phi(),
+            T::thousandth(),
+        ]
+    }
+
+    assert!(
+        float_function::<f64>()
+            == [
+                core::f64::consts::PI,
+                core::f64::consts::E,
+                core::f64::consts::SQRT_2,
+                core::f64::consts::TAU,
+                1.618033988749895,
+                1e-3
+            ]
+    );
+    assert!(
+        float_function::<f32>()
+            == [
+                core::f32::consts::PI,
+                core::f32::consts::E,
+                core::f32::consts::SQRT_2,
+                core::f32::consts::TAU,
+                1.618034,
+                1e-3
+            ]
+    );
+}
+
+#[test]
+fn test_traits_float() {
+    // `NumLazy` extends `Float`, so `Float` methods stay unambiguous alongside a `Float` bound.
+    fn with_float<T: NumLazy + num_traits::Float>(x: T) -> T {
+        (x.abs() + T::epsilon()).sqrt()
+    }
+
+    fn lazy_only<T: NumLazy>(x: T) -> T {
+        x.sqrt() * T::half()
+    }
+
+    assert!(with_float(-4.0_f64) == (4.0 + f64::EPSILON).sqrt());
+    assert!(lazy_only(16.0_f32) == 2.0);
+}
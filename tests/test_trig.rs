@@ -0,0 +1,40 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+mod common;
+
+use common::assert_close_ulps;
+use num_lazy::declare_nums;
+use num_traits::Float;
+
+declare_nums! {T}
+declare_nums! {@trig T}
+
+#[test]
+fn test_trig() {
+    // `sin`, `cos`, and `tan` of a rounded angle can be off by two ulps from the exact value.
+    fn float_function<T: Float>() {
+        assert_close_ulps(sin_30!(), pi_6!().sin(), 2);
+        assert_close_ulps(cos_30!(), pi_6!().cos(), 2);
+        assert_close_ulps(tan_30!(), pi_6!().tan(), 2);
+        assert_close_ulps(sin_45!(), pi_4!().sin(), 2);
+        assert_close_ulps(cos_45!(), pi_4!().cos(), 2);
+        assert_close_ulps(tan_45!(), pi_4!().tan(), 2);
+        assert_close_ulps(sin_60!(), pi_3!().sin(), 2);
+        assert_close_ulps(cos_60!(), pi_3!().cos(), 2);
+        assert_close_ulps(tan_60!(), pi_3!().tan(), 2);
+    }
+
+    fn exact_function<T: Float>() {
+        assert!(cos_30!() == half!() * sqrt_3!());
+        assert!(tan_60!() == sqrt_3!());
+        assert!(sin_45!() == frac_1_sqrt_2!());
+    }
+
+    float_function::<f64>();
+    float_function::<f32>();
+    exact_function::<f64>();
+    exact_function::<f32>();
+}
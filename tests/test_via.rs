@@ -0,0 +1,117 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+use core::ops::{Div, Mul};
+
+/// A fixed-point number in thousandths, without any `num_traits` impl.
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+pub struct Milli(i64);
+
+impl TryFrom<i64> for Milli {
+    type Error = &'static str;
+
+    fn try_from(n: i64) -> Result<Self, Self::Error> {
+        n.checked_mul(1000).map(Milli).ok_or("overflow")
+    }
+}
+
+impl Mul for Milli {
+    type Output = Milli;
+
+    fn mul(self, rhs: Milli) -> Milli {
+        Milli(self.0 * rhs.0 / 1000)
+    }
+}
+
+impl Div for Milli {
+    type Output = Milli;
+
+    fn div(self, rhs: Milli) -> Milli {
+        Milli(self.0 * 1000 / rhs.0)
+    }
+}
+
+mod try_from {
+    use super::Milli;
+    use num_lazy::declare_nums;
+
+    declare_nums! {@via Milli::try_from Milli}
+
+    pub fn values() -> [Milli; 4] {
+        [
+            num!(5),
+            frac!(1, 4),
+            pow!(3, 2),
+            num_or!(i64::MAX, num!(-1)),
+        ]
+    }
+
+    pub fn failed() -> Option<Milli> {
+        try_num!(i64::MAX)
+    }
+}
+
+mod function {
+    use super::Milli;
+    use num_lazy::declare_nums;
+
+    fn to_milli(n: f64) -> Option<Milli> {
+        Some(Milli((n * 1000.0) as i64))
+    }
+
+    declare_nums! {@via to_milli Milli}
+
+    pub fn values() -> [Milli; 3] {
+        [half!(), hundred!(), num!(2.5)]
+    }
+}
+
+mod text {
+    use num_lazy::{declare_nums, NumLazyError};
+
+    fn parse_u8(s: &str) -> Option<u8> {
+        s.parse().ok()
+    }
+
+    declare_nums! {@via parse_u8 u8}
+
+    // `&str` does not implement `ToPrimitive`, so it can only be checked for range.
+    pub fn checked(s: &str) -> Result<u8, NumLazyError> {
+        num_checked!(s)
+    }
+}
+
+mod narrowing {
+    use num_lazy::{declare_nums, NumLazyError};
+
+    declare_nums! {@via u8::try_from u8}
+
+    pub fn checked(n: i64) -> Result<u8, NumLazyError> {
+        num_checked!(n)
+    }
+
+    pub fn saturating(n: i64) -> u8 {
+        saturating_num!(n)
+    }
+}
+
+#[test]
+fn test_via_fallible() {
+    use num_lazy::NumLazyError;
+
+    assert!(text::checked("7") == Ok(7));
+    assert!(text::checked("300") == Err(NumLazyError::OutOfRange));
+    assert!(narrowing::checked(200) == Ok(200));
+    assert!(narrowing::checked(-1) == Err(NumLazyError::OutOfRange));
+    assert!(narrowing::saturating(300) == 255);
+    assert!(narrowing::saturating(-5) == 0);
+}
+
+#[test]
+fn test_via() {
+    assert!(try_from::values() == [Milli(5000), Milli(250), Milli(9000), Milli(-1000)]);
+    assert!(try_from::failed().is_none());
+    assert!(function::values() == [Milli(500), Milli(100_000), Milli(2500)]);
+}
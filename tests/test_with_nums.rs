@@ -0,0 +1,32 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+#![cfg(feature = "macros")]
+
+use num_lazy::with_nums;
+use num_traits::{Float, PrimInt};
+
+#[with_nums(T)]
+fn area<T: Float>(radius: T) -> T {
+    pi!() * radius * radius
+}
+
+#[with_nums(@ops T)]
+fn mean_sq<T: Float>(a: T, b: T) -> T {
+    midpoint!(square!(a), square!(b))
+}
+
+#[with_nums(T, except[half])]
+fn half<T: PrimInt>(n: T) -> T {
+    n / two!()
+}
+
+#[test]
+fn test_with_nums() {
+    assert!(area(1.0_f64) == core::f64::consts::PI);
+    assert!(area(2.0_f32) == 4.0 * core::f32::consts::PI);
+    assert!(mean_sq(1.0_f64, 3.0) == 5.0);
+    assert!(half(9_u8) == 4);
+}
@@ -0,0 +1,40 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+// Local `core` and `std` modules must not capture the paths used by the generated macros.
+
+mod generic {
+    #[allow(dead_code)]
+    mod core {}
+    #[allow(dead_code)]
+    mod std {}
+
+    use num_traits::Float;
+
+    num_lazy::declare_nums! {T}
+    num_lazy::declare_nums! {@angle T}
+
+    pub fn float_function<T: Float>() -> T {
+        pi!() + deg!(180) + checked_div!(one!(), zero!()).unwrap_or(zero!())
+    }
+}
+
+mod concrete {
+    #[allow(dead_code)]
+    mod core {}
+    #[allow(dead_code)]
+    mod std {}
+
+    num_lazy::declare_nums! {f64}
+
+    pub fn pi() -> f64 {
+        pi!() + num_checked!(1).unwrap()
+    }
+}
+
+fn main() {
+    assert!(generic::float_function::<f64>() == 2.0 * ::core::f64::consts::PI);
+    assert!(concrete::pi() == ::core::f64::consts::PI + 1.0);
+}
@@ -0,0 +1,14 @@
+/*
+ * num-lazy is licensed under The 3-Clause BSD, see LICENSE.
+ * Copyright 2025 Sira Pornsiriprasert <code@psira.me>
+ */
+
+// Without `@strict`, unused generated macros are not reported, even when the lint is denied.
+
+#![deny(unused_macros)]
+
+num_lazy::declare_nums! {f64}
+
+fn main() {
+    assert!(pi!() == core::f64::consts::PI);
+}